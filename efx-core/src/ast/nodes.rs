@@ -23,6 +23,8 @@ pub struct Element {
 pub struct Attr {
     pub name: String,
     pub value: String,
+    /// `true` for `attr={expr}` (`value` is the expression source), `false` for quoted values and flags.
+    pub expr: bool,
     pub span: SpanRange,
 }
 
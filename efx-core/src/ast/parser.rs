@@ -1,14 +1,14 @@
 use crate::ast::error::ParseError;
 use crate::ast::lexer::Lexer;
 use crate::ast::nodes::{Attr, Element, Interpolation, Node, Text};
-use crate::ast::span_range::SpanRange;
+use crate::ast::span_range::{Pos, SpanRange};
 use crate::ast::tok::Tok;
 use crate::ast::PResult;
 
 pub struct Parser<'a> {
     toks: Vec<(Tok, SpanRange)>,
     i: usize,
-    _src: &'a str,
+    src: &'a str,
 }
 
 impl<'a> Parser<'a> {
@@ -16,7 +16,7 @@ impl<'a> Parser<'a> {
         Self {
             toks: Lexer::new(src).all(),
             i: 0,
-            _src: src,
+            src,
         }
     }
 
@@ -26,7 +26,7 @@ impl<'a> Parser<'a> {
             match self.cur() {
                 Some((Tok::LAngle, _)) => nodes.push(self.parse_element()?),
                 Some((Tok::LBrace, _)) => nodes.push(self.parse_i11n()?),
-                Some((Tok::Text(_), _)) => nodes.push(self.parse_text()?),
+                Some((Tok::Text(_) | Tok::Slash, _)) => nodes.push(self.parse_text()?),
                 Some((tok, sp)) => {
                     return Err(ParseError {
                         msg: format!("unexpected token: {:?}", tok),
@@ -131,8 +131,9 @@ impl<'a> Parser<'a> {
 
         loop {
             match self.cur() {
-                Some((Tok::Slash, _)) => {
-                    return if let Some((Tok::RAngle, sp_gt)) = self.toks.get(self.i + 1).cloned() {
+                Some((Tok::Slash, sp)) => {
+                    let sp = *sp;
+                    if let Some((Tok::RAngle, sp_gt)) = self.toks.get(self.i + 1).cloned() {
                         self.bump(); // '/'
                         self.bump(); // '>'
 
@@ -144,19 +145,21 @@ impl<'a> Parser<'a> {
                                 end: attrs_span_end.unwrap_or(start),
                             },
                         )?;
-                        Ok(Node::Element(Element {
+                        return Ok(Node::Element(Element {
                             name,
                             attrs,
                             children: vec![],
                             span: SpanRange { start, end },
-                        }))
-                    } else {
-                        let sp = self.cur().unwrap().1;
-                        Err(ParseError {
-                            msg: "unexpected '/' in tag head".into(),
-                            span: sp,
-                        })
-                    };
+                        }));
+                    }
+
+                    // A '/' right after `{expr}` inside a quoted value, e.g. text="{n}/{total}"
+                    if attrs_span_start.is_none() {
+                        attrs_span_start = Some(sp.start);
+                    }
+                    attrs_span_end = Some(sp.end);
+                    attrs_src.push('/');
+                    self.bump();
                 }
                 Some((Tok::RAngle, sp_gt)) => {
                     // Closed the opening tag: now parse the children or end empty
@@ -204,7 +207,9 @@ impl<'a> Parser<'a> {
                                 }
                             }
                             Some((Tok::LBrace, _)) => children.push(self.parse_i11n()?),
-                            Some((Tok::Text(_), _)) => children.push(self.parse_text()?),
+                            Some((Tok::Text(_) | Tok::Slash, _)) => {
+                                children.push(self.parse_text()?)
+                            }
                             Some((tok, sp)) => {
                                 return Err(ParseError {
                                     msg: format!("unexpected token in element body: {:?}", tok),
@@ -229,6 +234,16 @@ impl<'a> Parser<'a> {
                     attrs_src.push_str(s);
                     self.bump();
                 }
+                Some((Tok::LBrace, sp)) => {
+                    // Expression value: attr={expr}
+                    let sp = *sp;
+                    if attrs_span_start.is_none() {
+                        attrs_span_start = Some(sp.start);
+                    }
+                    let (raw, end) = self.take_braced_raw(sp)?;
+                    attrs_span_end = Some(end);
+                    attrs_src.push_str(&raw);
+                }
                 Some((tok, sp)) => {
                     return Err(ParseError {
                         msg: format!("unexpected token in tag head: {:?}", tok),
@@ -245,18 +260,69 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Reads a `{ ... }` attribute value straight from the source (braces included),
+    /// so nested braces, `<`/`>` and string literals inside the expression survive.
+    /// Tokens covered by the value are skipped.
+    fn take_braced_raw(&mut self, open: SpanRange) -> PResult<(String, Pos)> {
+        let start = open.start.0;
+        let end = match matching_brace_end(self.src.as_bytes(), start) {
+            Some(e) => e,
+            None => {
+                return Err(ParseError {
+                    msg: "unterminated '{' in attribute value".into(),
+                    span: SpanRange::new(start, self.src.len()),
+                });
+            }
+        };
+
+        // Skip tokens inside the value; split the one that crosses its end
+        while let Some((tok, sp)) = self.cur().cloned() {
+            if sp.start.0 >= end {
+                break;
+            }
+            self.bump();
+            if sp.end.0 > end {
+                match tok {
+                    Tok::Text(s) => {
+                        let cut = end - sp.start.0;
+                        self.toks.insert(
+                            self.i,
+                            (
+                                Tok::Text(s[cut..].to_string()),
+                                SpanRange::new(end, sp.end.0),
+                            ),
+                        );
+                    }
+                    _ => {
+                        return Err(ParseError {
+                            msg: "unbalanced '}' in attribute value".into(),
+                            span: sp,
+                        });
+                    }
+                }
+                break;
+            }
+        }
+
+        Ok((self.src[start..end].to_string(), Pos(end)))
+    }
+
     fn parse_text(&mut self) -> PResult<Node> {
         let mut start = None;
         let mut end = None;
         let mut buf = String::new();
 
-        while let Some((Tok::Text(s), sp)) = self.cur().cloned() {
+        // A lone '/' (e.g. after an interpolation: `{a}/{b}`) is plain text as well
+        while let Some((tok @ (Tok::Text(_) | Tok::Slash), sp)) = self.cur().cloned() {
             if start.is_none() {
                 start = Some(sp.start);
             }
             end = Some(sp.end);
             self.bump();
-            buf.push_str(&s);
+            match tok {
+                Tok::Text(s) => buf.push_str(&s),
+                _ => buf.push('/'),
+            }
         }
 
         if let (Some(st), Some(en)) = (start, end) {
@@ -354,17 +420,47 @@ fn parse_attrs_from_buffer(buf: &str, span: SpanRange) -> PResult<Vec<Attr>> {
         let name = &buf[name_start..i];
         i = skip_ws(bytes, i);
         if i >= bytes.len() || bytes[i] != b'=' {
-            return Err(ParseError {
-                msg: format!("expected '=' after attribute '{}'", name),
+            // Boolean flag: <Slider logarithmic/> → logarithmic="true"
+            attrs.push(Attr {
+                name: name.to_string(),
+                value: "true".to_string(),
+                expr: false,
                 span,
             });
+            continue;
         }
         i += 1;
         i = skip_ws(bytes, i);
 
+        if i < bytes.len() && bytes[i] == b'{' {
+            // Expression: attr={expr} → raw expression source without braces
+            let val_start = i + 1;
+            i = match matching_brace_end(bytes, i) {
+                Some(e) => e - 1,
+                None => {
+                    return Err(ParseError {
+                        msg: format!("unterminated '{{' in attribute '{}'", name),
+                        span,
+                    });
+                }
+            };
+            let value = buf[val_start..i].trim();
+            i += 1; // closing brace
+
+            attrs.push(Attr {
+                name: name.to_string(),
+                value: value.to_string(),
+                expr: true,
+                span,
+            });
+
+            i = skip_ws(bytes, i);
+            continue;
+        }
+
         if i >= bytes.len() || (bytes[i] != b'"' && bytes[i] != b'\'') {
             return Err(ParseError {
-                msg: format!("expected quote after '=' in attribute '{}'", name),
+                msg: format!("expected quote or '{{' after '=' in attribute '{}'", name),
                 span,
             });
         }
@@ -386,6 +482,7 @@ fn parse_attrs_from_buffer(buf: &str, span: SpanRange) -> PResult<Vec<Attr>> {
         attrs.push(Attr {
             name: name.to_string(),
             value: value.to_string(),
+            expr: false,
             span,
         });
 
@@ -394,3 +491,46 @@ fn parse_attrs_from_buffer(buf: &str, span: SpanRange) -> PResult<Vec<Attr>> {
 
     Ok(attrs)
 }
+
+/// Returns the index just past the `}` matching the `{` at `open`.
+/// String and char literals are skipped, so braces inside them are not counted.
+fn matching_brace_end(bytes: &[u8], open: usize) -> Option<usize> {
+    let mut depth = 0usize;
+    let mut j = open;
+
+    while j < bytes.len() {
+        match bytes[j] {
+            b'{' => depth += 1,
+            b'}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(j + 1);
+                }
+            }
+            b'"' => {
+                j += 1;
+                while j < bytes.len() && bytes[j] != b'"' {
+                    if bytes[j] == b'\\' {
+                        j += 1;
+                    }
+                    j += 1;
+                }
+            }
+            // `'}'`, `'\''`, `'\u{7d}'`; a quote without a closing one is a lifetime (`'a`)
+            b'\'' => {
+                if bytes.get(j + 1) == Some(&b'\\') {
+                    j += 3;
+                    while j < bytes.len() && bytes[j] != b'\'' {
+                        j += 1;
+                    }
+                } else if bytes.get(j + 2) == Some(&b'\'') {
+                    j += 2;
+                }
+            }
+            _ => {}
+        }
+        j += 1;
+    }
+
+    None
+}
@@ -42,6 +42,44 @@ pub fn parse_f32(name: &str, s: &str) -> Result<f32, String> {
     })
 }
 
+pub fn parse_f64(name: &str, s: &str) -> Result<f64, String> {
+    s.trim().parse::<f64>().map_err(|_| {
+        format!(
+            "efx: attribute `{}` expects number (f64), got `{}`",
+            name, s
+        )
+    })
+}
+
+/// Inclusive numeric range `A..=B` → (A, B), requires `A <= B`
+pub fn parse_range(name: &str, s: &str) -> Result<(f64, f64), String> {
+    let (lo, hi) = match s.trim().split_once("..=") {
+        Some(parts) => parts,
+        None => {
+            return Err(format!(
+                "efx: attribute `{}` expects inclusive range `min..=max`, got `{}`",
+                name, s
+            ))
+        }
+    };
+    let min = parse_f64(name, lo)?;
+    let max = parse_f64(name, hi)?;
+    check_range(name, min, max)?;
+    Ok((min, max))
+}
+
+/// Bounds given as separate attributes (e.g. `min`/`max`) must satisfy `min <= max`
+pub fn check_range(name: &str, min: f64, max: f64) -> Result<(), String> {
+    if min <= max {
+        Ok(())
+    } else {
+        Err(format!(
+            "efx: attribute `{}` expects min <= max, got {}..={}",
+            name, min, max
+        ))
+    }
+}
+
 /// Returns the index of the matched option from allowed
 pub fn parse_enum(name: &str, s: &str, allowed: &[&str]) -> Result<usize, String> {
     let val = s.trim().to_ascii_lowercase();
@@ -54,6 +54,14 @@ impl Ui {
     #[inline]
     pub fn hyperlink_to<T1: Into<String>, T2: Into<String>>(&mut self, _label: T1, _url: T2) {}
     #[inline]
+    pub fn radio_value<V: PartialEq, T>(&mut self, _current: &mut V, _alt: V, _text: T) -> Resp {
+        Resp
+    }
+    #[inline]
+    pub fn style(&self) -> std::sync::Arc<egui::Style> {
+        std::sync::Arc::default()
+    }
+    #[inline]
     pub fn ctx(&self) -> egui::Context {
         egui::Context::default()
     }
@@ -1,4 +1,6 @@
-use efx_core::attr::{parse_bool, parse_color_rgba, parse_enum, parse_f32, Rgba};
+use efx_core::attr::{
+    check_range, parse_bool, parse_color_rgba, parse_enum, parse_f32, parse_range, Rgba,
+};
 
 fn rgba(r: u8, g: u8, b: u8, a: u8) -> Rgba {
     Rgba { r, g, b, a }
@@ -89,3 +91,29 @@ fn parse_color_hex_err() {
     let e = parse_color_rgba("color", "112233").unwrap_err();
     assert!(e.contains("expects color name"));
 }
+
+#[test]
+fn parse_range_ok() {
+    assert_eq!(parse_range("range", "0..=10").unwrap(), (0.0, 10.0));
+    assert_eq!(parse_range("range", " -1.5 ..= 2 ").unwrap(), (-1.5, 2.0));
+    assert_eq!(parse_range("range", "3..=3").unwrap(), (3.0, 3.0));
+}
+
+#[test]
+fn parse_range_err() {
+    let e = parse_range("range", "0..10").unwrap_err();
+    assert!(e.contains("min..=max"));
+
+    let e = parse_range("range", "a..=10").unwrap_err();
+    assert!(e.contains("expects number"));
+
+    let e = parse_range("range", "10..=0").unwrap_err();
+    assert!(e.contains("min <= max"));
+}
+
+#[test]
+fn check_range_bounds() {
+    assert!(check_range("max", 0.0, 100.0).is_ok());
+    let e = check_range("max", 5.0, 1.0).unwrap_err();
+    assert!(e.contains("min <= max"));
+}
@@ -67,3 +67,118 @@ fn self_closed_with_attrs() {
         _ => panic!("expected element"),
     }
 }
+
+#[test]
+fn attr_expression_value() {
+    let src = r#"<Slider value={&mut state.volume} max="100"/>"#;
+    let ast = parse_str(src).unwrap();
+    match &ast[0] {
+        Node::Element(el) => {
+            assert_eq!(el.attrs.len(), 2);
+            assert_eq!(el.attrs[0].name, "value");
+            assert_eq!(el.attrs[0].value, "&mut state.volume");
+            assert!(el.attrs[0].expr);
+            assert_eq!(el.attrs[1].name, "max");
+            assert_eq!(el.attrs[1].value, "100");
+            assert!(!el.attrs[1].expr);
+        }
+        _ => panic!("expected element"),
+    }
+}
+
+#[test]
+fn attr_expression_with_braces_and_angles() {
+    let src = r#"<Button onClick={|| { if n > 3 { log("}") } }}>Go</Button>"#;
+    let ast = parse_str(src).unwrap();
+    match &ast[0] {
+        Node::Element(el) => {
+            assert_eq!(el.attrs.len(), 1);
+            assert_eq!(el.attrs[0].value, r#"|| { if n > 3 { log("}") } }"#);
+            assert_eq!(el.children.len(), 1);
+        }
+        _ => panic!("expected element"),
+    }
+}
+
+#[test]
+fn attr_expression_with_char_literals() {
+    let src =
+        r#"<Button onClick={|| { push('}'); push('{'); push('\''); }} title={'}'}>Go</Button>"#;
+    let ast = parse_str(src).unwrap();
+    match &ast[0] {
+        Node::Element(el) => {
+            assert_eq!(el.attrs.len(), 2);
+            assert_eq!(
+                el.attrs[0].value,
+                r#"|| { push('}'); push('{'); push('\''); }"#
+            );
+            assert_eq!(el.attrs[1].value, "'}'");
+            assert_eq!(el.children.len(), 1);
+        }
+        _ => panic!("expected element"),
+    }
+}
+
+#[test]
+fn attr_expression_with_lifetime() {
+    let src = r#"<Label text={Cow::<'static, str>::from("}")}/>"#;
+    let ast = parse_str(src).unwrap();
+    match &ast[0] {
+        Node::Element(el) => {
+            assert_eq!(el.attrs[0].value, r#"Cow::<'static, str>::from("}")"#);
+        }
+        _ => panic!("expected element"),
+    }
+}
+
+#[test]
+fn attr_boolean_flag() {
+    let src = r#"<Slider logarithmic value={v} clamp/>"#;
+    let ast = parse_str(src).unwrap();
+    match &ast[0] {
+        Node::Element(el) => {
+            let attrs: Vec<(&str, &str)> = el
+                .attrs
+                .iter()
+                .map(|a| (a.name.as_str(), a.value.as_str()))
+                .collect();
+            assert_eq!(
+                attrs,
+                vec![("logarithmic", "true"), ("value", "v"), ("clamp", "true")]
+            );
+        }
+        _ => panic!("expected element"),
+    }
+}
+
+#[test]
+fn attr_expression_unterminated() {
+    let err = parse_str("<Slider value={v/>").unwrap_err();
+    assert!(err.msg.contains("unterminated"));
+}
+
+#[test]
+fn attr_text_with_interpolations() {
+    let src = r#"<ProgressBar value={done} text="Uploading {n}/{total}"/>"#;
+    let ast = parse_str(src).unwrap();
+    match &ast[0] {
+        Node::Element(el) => {
+            assert_eq!(el.attrs.len(), 2);
+            assert_eq!(el.attrs[1].name, "text");
+            assert_eq!(el.attrs[1].value, "Uploading {n}/{total}");
+        }
+        _ => panic!("expected element"),
+    }
+}
+
+#[test]
+fn slash_after_interpolation_is_text() {
+    let ast = parse_str("<Label>{a}/{b}</Label>").unwrap();
+    match &ast[0] {
+        Node::Element(el) => {
+            assert_eq!(el.children.len(), 3);
+            assert!(matches!(&el.children[1], Node::Text(Text { value, .. }) if value == "/"));
+        }
+        _ => panic!("expected element"),
+    }
+}
@@ -20,7 +20,7 @@ impl eframe::App for App {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
         egui::CentralPanel::default().show(ctx, |ui| {
             // Header
-            let _ = efx!(
+            efx!(
                 ui,
                 r#"
                 <Column gap="8">
@@ -44,13 +44,13 @@ impl eframe::App for App {
             });
 
             // Dynamic text via {expr}
-            let _ = efx!(ui, r#"<Label>Counter: {self.counter}</Label>"#);
+            efx!(ui, r#"<Label>Counter: {self.counter}</Label>"#);
 
             // Input field (binding directly to the state field)
-            let _ = efx!(ui, r#"<TextField value="self.input" hint="type here…"/>"#);
+            efx!(ui, r#"<TextField value="self.input" hint="type here…"/>"#);
 
             // Scrolling + different tags
-            let _ = efx!(
+            efx!(
                 ui,
                 r##"
                 <ScrollArea axis="vertical" max-height="160" always-show="true" id="demo-log">
@@ -18,6 +18,21 @@ pub fn parse_f32(name: &str, s: &str) -> Result<f32, String> {
     core_attr::parse_f32(name, s)
 }
 
+#[inline]
+pub fn parse_f64(name: &str, s: &str) -> Result<f64, String> {
+    core_attr::parse_f64(name, s)
+}
+
+#[inline]
+pub fn parse_range(name: &str, s: &str) -> Result<(f64, f64), String> {
+    core_attr::parse_range(name, s)
+}
+
+#[inline]
+pub fn check_range(name: &str, min: f64, max: f64) -> Result<(), String> {
+    core_attr::check_range(name, min, max)
+}
+
 #[inline]
 pub fn parse_enum(name: &str, s: &str, allowed: &[&str]) -> Result<usize, String> {
     core_attr::parse_enum(name, s, allowed)
@@ -0,0 +1,387 @@
+use crate::utils::attr::attr_map;
+use crate::utils::buffer::build_buffer_from_attr;
+use crate::utils::render::render_children_stmt;
+use efx_core::{Attr, Element, Node};
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote, ToTokens};
+use syn::visit_mut::VisitMut;
+use syn::{FnArg, Ident, ItemFn, Lifetime, Pat, Type};
+
+/// `true` for tag names that resolve to a component: PascalCase identifiers (`<Toolbar>`).
+/// All-caps names (`<WAT>`) stay unknown tags.
+pub fn is_component_name(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_uppercase())
+        && name.chars().any(|c| c.is_ascii_lowercase())
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// `#[efx_component]` over `fn Name(ui: &mut egui::Ui, prop: T, ...)`.
+///
+/// The function is kept as is, and `NameProps` gets one field per parameter after `ui`.
+/// `efx!` renders `<Name prop=.../>` as `NameProps { prop: ... }.__efx_render(ui)`, so a missing,
+/// unknown or mistyped prop is reported by rustc on the props struct.
+pub fn expand(args: TokenStream, item: ItemFn) -> TokenStream {
+    if !args.is_empty() {
+        return syn::Error::new_spanned(args, "efx: #[efx_component] takes no arguments")
+            .to_compile_error();
+    }
+    match expand_fn(item) {
+        Ok(ts) => ts,
+        Err(err) => err.to_compile_error(),
+    }
+}
+
+fn expand_fn(mut item: ItemFn) -> syn::Result<TokenStream> {
+    let slots = take_slot_attrs(&mut item)?;
+    let sig = &item.sig;
+    let name = &sig.ident;
+    let vis = &item.vis;
+    let props = format_ident!("{}Props", name);
+
+    let mut inputs = sig.inputs.iter();
+    let ui_ty = match inputs.next() {
+        Some(FnArg::Typed(arg)) => &arg.ty,
+        Some(FnArg::Receiver(recv)) => {
+            return Err(syn::Error::new_spanned(
+                recv,
+                "efx: a component is a free function, `self` is not supported",
+            ))
+        }
+        None => {
+            return Err(syn::Error::new_spanned(
+                sig,
+                "efx: a component takes `ui: &mut egui::Ui` as the first parameter",
+            ))
+        }
+    };
+    if slots[0] {
+        return Err(syn::Error::new_spanned(
+            ui_ty,
+            "efx: the first parameter of a component is the `Ui`, it cannot be a slot",
+        ));
+    }
+
+    let lifetime = Lifetime::new("'efx", Span::call_site());
+    let mut elided = ElidedLifetimes {
+        lifetime: lifetime.clone(),
+        used: false,
+    };
+    let mut generics = sig.generics.clone();
+    let mut fields = Vec::new();
+    let mut types = Vec::new();
+    for (input, is_slot) in inputs.zip(slots.into_iter().skip(1)) {
+        let FnArg::Typed(arg) = input else {
+            unreachable!("`self` can only be the first parameter");
+        };
+        let field =
+            match &*arg.pat {
+                Pat::Ident(pat) => pat.ident.clone(),
+                other => return Err(syn::Error::new_spanned(
+                    other,
+                    "efx: component parameters must be plain identifiers, they become prop names",
+                )),
+            };
+
+        let ty = match &*arg.ty {
+            // A slot `impl FnOnce(&mut egui::Ui)` becomes a type parameter of the props
+            Type::ImplTrait(it) if is_slot => {
+                let param = format_ident!("__EfxSlot{}", fields.len());
+                let bounds = &it.bounds;
+                generics.params.push(syn::parse_quote!(#param: #bounds));
+                syn::parse_quote!(#param)
+            }
+            ty => {
+                if let Some(impl_trait) = find_impl_trait(ty) {
+                    return Err(syn::Error::new_spanned(
+                        impl_trait,
+                        "efx: `impl Trait` component parameters are only supported for `#[efx_slot]`, \
+                         use a generic parameter",
+                    ));
+                }
+                // References in props borrow from the template scope: elided lifetimes become `'efx`
+                let mut ty = ty.clone();
+                elided.visit_type_mut(&mut ty);
+                ty
+            }
+        };
+        fields.push(field);
+        types.push(ty);
+    }
+
+    if elided.used {
+        generics.params.insert(0, syn::parse_quote!(#lifetime));
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let output = &sig.output;
+    let doc = format!("Props of [`{}`], generated by `#[efx_component]`.", name);
+
+    Ok(quote! {
+        #[allow(non_snake_case)]
+        #item
+
+        #[doc = #doc]
+        #vis struct #props #impl_generics #where_clause {
+            #( #vis #fields: #types, )*
+        }
+
+        impl #impl_generics #props #ty_generics #where_clause {
+            #[doc(hidden)]
+            #vis fn __efx_render(self, ui: #ui_ty) #output {
+                #name(ui #(, self.#fields)*)
+            }
+        }
+    })
+}
+
+/// Removes `#[efx_slot]` from the parameters, rustc does not know it.
+/// Returns which parameters had it.
+fn take_slot_attrs(item: &mut ItemFn) -> syn::Result<Vec<bool>> {
+    let mut slots = Vec::new();
+    for input in item.sig.inputs.iter_mut() {
+        let attrs = match input {
+            FnArg::Typed(arg) => &mut arg.attrs,
+            FnArg::Receiver(recv) => &mut recv.attrs,
+        };
+        let mut is_slot = false;
+        for attr in attrs.iter() {
+            if attr.path().is_ident("efx_slot") {
+                attr.meta.require_path_only()?;
+                is_slot = true;
+            }
+        }
+        attrs.retain(|attr| !attr.path().is_ident("efx_slot"));
+        slots.push(is_slot);
+    }
+    Ok(slots)
+}
+
+/// Replaces `&T` and `'_` with the props lifetime, except inside `fn(..)` and `Fn(..)` signatures.
+struct ElidedLifetimes {
+    lifetime: Lifetime,
+    used: bool,
+}
+
+impl VisitMut for ElidedLifetimes {
+    fn visit_type_reference_mut(&mut self, r: &mut syn::TypeReference) {
+        if r.lifetime.is_none() {
+            r.lifetime = Some(self.lifetime.clone());
+            self.used = true;
+        }
+        syn::visit_mut::visit_type_reference_mut(self, r);
+    }
+
+    fn visit_lifetime_mut(&mut self, lt: &mut Lifetime) {
+        if lt.ident == "_" {
+            *lt = self.lifetime.clone();
+            self.used = true;
+        }
+    }
+
+    fn visit_type_bare_fn_mut(&mut self, _: &mut syn::TypeBareFn) {}
+
+    fn visit_parenthesized_generic_arguments_mut(
+        &mut self,
+        _: &mut syn::ParenthesizedGenericArguments,
+    ) {
+    }
+}
+
+fn find_impl_trait(ty: &Type) -> Option<&syn::TypeImplTrait> {
+    match ty {
+        Type::ImplTrait(it) => Some(it),
+        Type::Reference(r) => find_impl_trait(&r.elem),
+        Type::Paren(p) => find_impl_trait(&p.elem),
+        Type::Group(g) => find_impl_trait(&g.elem),
+        Type::Slice(s) => find_impl_trait(&s.elem),
+        Type::Array(a) => find_impl_trait(&a.elem),
+        Type::Ptr(p) => find_impl_trait(&p.elem),
+        Type::Tuple(t) => t.elems.iter().find_map(find_impl_trait),
+        Type::Path(p) => p.path.segments.iter().find_map(|seg| match &seg.arguments {
+            syn::PathArguments::AngleBracketed(args) => args.args.iter().find_map(|a| match a {
+                syn::GenericArgument::Type(t) => find_impl_trait(t),
+                _ => None,
+            }),
+            _ => None,
+        }),
+        _ => None,
+    }
+}
+
+/// `<Name prop="..." other={expr}>...</Name>`: builds `NameProps` and renders it against `ui`.
+/// `<Slot name="x">` children fill the slot `x`, the other children the default slot `children`.
+pub fn render_component<UI: ToTokens>(ui: &UI, el: &Element) -> TokenStream {
+    match component_call(ui, el) {
+        Ok(ts) => ts,
+        Err(err) => err,
+    }
+}
+
+fn component_call<UI: ToTokens>(ui: &UI, el: &Element) -> Result<TokenStream, TokenStream> {
+    let props = format_ident!("{}Props", el.name);
+    let mut fields = Vec::new();
+    let mut values = Vec::new();
+    // Interpolated text props are built before the props struct, which borrows them
+    let mut texts = TokenStream::new();
+    for (i, attr) in el.attrs.iter().enumerate() {
+        fields.push(prop_ident(&el.name, &attr.name)?);
+        values.push(prop_value(
+            attr,
+            &format_ident!("__efx_prop_{}", i),
+            &mut texts,
+        )?);
+    }
+
+    let mut default_slot = Vec::new();
+    for ch in &el.children {
+        match ch {
+            Node::Text(t) if t.value.trim().is_empty() => {}
+            Node::Element(slot) if slot.name == "Slot" => {
+                let map = attr_map(slot, &["name"], "Slot")?;
+                let Some(name) = map.get("name") else {
+                    return Err(
+                        quote! { compile_error!("efx: <Slot> requires `name` attribute"); },
+                    );
+                };
+                fields.push(prop_ident(&el.name, name)?);
+                values.push(slot_closure(&slot.children));
+            }
+            _ => default_slot.push(ch.clone()),
+        }
+    }
+    // No children, no default slot: a component that needs one fails on the missing `children` field
+    if !default_slot.is_empty() {
+        fields.push(format_ident!("children"));
+        values.push(slot_closure(&default_slot));
+    }
+
+    Ok(quote! {{
+        #texts
+        #props { #( #fields: #values, )* }.__efx_render(#ui);
+    }})
+}
+
+/// Slot content, compiled as a closure over the `Ui` the component passes in.
+fn slot_closure(children: &[Node]) -> TokenStream {
+    let body = render_children_stmt(&quote!(ui), children);
+    quote! {
+        |ui: &mut egui::Ui| { #body }
+    }
+}
+
+/// Field of the props struct: `on-save` → `on_save`, keywords as raw identifiers.
+fn prop_ident(tag: &str, name: &str) -> Result<Ident, TokenStream> {
+    let field = name.replace('-', "_");
+    syn::parse_str::<Ident>(&field)
+        .or_else(|_| syn::parse_str::<Ident>(&format!("r#{}", field)))
+        .map_err(|_| {
+            let msg = format!(
+                "efx: <{}> attribute `{}` is not a valid prop name",
+                tag, name
+            );
+            quote! { compile_error!(#msg); }
+        })
+}
+
+/// `{expr}` is passed as is; quoted `true`/`false` become literals, numbers become a literal of the
+/// prop type (see `number_value`); any other text goes through `Into`, so that `&str` and `String`
+/// props both accept it. Text with `{expr}` interpolations is built into `var`, declared in `texts`.
+fn prop_value(
+    attr: &Attr,
+    var: &Ident,
+    texts: &mut TokenStream,
+) -> Result<TokenStream, TokenStream> {
+    let value = attr.value.as_str();
+    if attr.expr {
+        return syn::parse_str::<syn::Expr>(value)
+            .map(|e| e.into_token_stream())
+            .map_err(|_| {
+                let msg = format!(
+                    "efx: attribute `{}` must be a valid Rust expression, got `{}`",
+                    attr.name, value
+                );
+                quote! { compile_error!(#msg); }
+            });
+    }
+
+    if let Some(number) = number_value(value) {
+        return Ok(number);
+    }
+    match value {
+        "true" => Ok(quote!(true)),
+        "false" => Ok(quote!(false)),
+        _ if value.contains(['{', '}']) => {
+            let (init, build) = build_buffer_from_attr(&attr.name, value)?;
+            texts.extend(quote! {
+                let #var = { #init #build __efx_buf };
+            });
+            Ok(quote!(::core::convert::Into::into(#var.as_str())))
+        }
+        _ => Ok(quote!(::core::convert::Into::into(#value))),
+    }
+}
+
+/// Number-looking text, e.g. `step="2"` or `year="2024"`: the prop type picks the literal through a
+/// local trait, implemented for the numeric types the value fits in and for `&str`/`String`, so the
+/// same text fits `i32`, `f32` and text props alike.
+fn number_value(value: &str) -> Option<TokenStream> {
+    let finite = value.parse::<f64>().map(f64::is_finite).unwrap_or(false);
+    if !finite || syn::parse_str::<syn::Expr>(value).is_err() {
+        return None;
+    }
+
+    let mut types: Vec<&str> = Vec::new();
+    if let Ok(n) = value.parse::<i128>() {
+        let fits = [
+            ("i8", i8::MIN as i128, i8::MAX as i128),
+            ("i16", i16::MIN as i128, i16::MAX as i128),
+            ("i32", i32::MIN as i128, i32::MAX as i128),
+            ("i64", i64::MIN as i128, i64::MAX as i128),
+            ("i128", i128::MIN, i128::MAX),
+            ("isize", isize::MIN as i128, isize::MAX as i128),
+            ("u8", 0, u8::MAX as i128),
+            ("u16", 0, u16::MAX as i128),
+            ("u32", 0, u32::MAX as i128),
+            ("u64", 0, u64::MAX as i128),
+            ("u128", 0, i128::MAX),
+            ("usize", 0, usize::MAX as i128),
+        ];
+        types.extend(
+            fits.iter()
+                .filter(|(_, min, max)| (*min..=*max).contains(&n))
+                .map(|(ty, _, _)| *ty),
+        );
+    }
+    if value.parse::<f32>().is_ok_and(f32::is_finite) {
+        types.push("f32");
+    }
+    types.push("f64");
+
+    // `1.` takes a suffix only as `1.0`
+    let digits = match value.strip_suffix('.') {
+        Some(int) => format!("{}.0", int),
+        None => value.to_string(),
+    };
+    let mut impls = TokenStream::new();
+    for ty in types {
+        let ty_ts = format_ident!("{}", ty);
+        let lit: TokenStream = format!("{}{}", digits, ty).parse().ok()?;
+        impls.extend(quote! {
+            impl __EfxNumber for #ty_ts {
+                fn __efx_number() -> Self { #lit }
+            }
+        });
+    }
+    Some(quote! {{
+        trait __EfxNumber {
+            fn __efx_number() -> Self;
+        }
+        #impls
+        impl<'a> __EfxNumber for &'a str {
+            fn __efx_number() -> Self { #value }
+        }
+        impl __EfxNumber for ::std::string::String {
+            fn __efx_number() -> Self { ::std::string::String::from(#value) }
+        }
+        __EfxNumber::__efx_number()
+    }})
+}
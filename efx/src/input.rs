@@ -2,14 +2,24 @@ use syn::{Expr, LitStr};
 
 pub(crate) struct EfxInput {
     pub(crate) ui: Expr,
+    /// `efx!(ctx: expr, ...)`: `ui` is an `egui::Context`, the root may only hold panels and windows.
+    pub(crate) ctx: bool,
     pub(crate) template: LitStr,
 }
 
 impl syn::parse::Parse for EfxInput {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let ctx = input.peek(syn::Ident)
+            && input.peek2(syn::Token![:])
+            && !input.peek2(syn::Token![::])
+            && input.fork().parse::<syn::Ident>()? == "ctx";
+        if ctx {
+            input.parse::<syn::Ident>()?;
+            input.parse::<syn::Token![:]>()?;
+        }
         let ui = input.parse::<Expr>()?;
         input.parse::<syn::Token![,]>()?;
         let template = input.parse::<LitStr>()?;
-        Ok(EfxInput { ui, template })
+        Ok(EfxInput { ui, ctx, template })
     }
 }
@@ -5,6 +5,7 @@
 #![doc = include_str!("../docs/guide.md")]
 
 mod attr_adapters;
+mod component;
 mod input;
 mod render;
 mod tags;
@@ -40,6 +41,19 @@ use efx_core::{parse_str, Node};
 /// "#);
 /// ```
 ///
+/// With `ctx:` before the first argument, the template is laid out on an `egui::Context`
+/// (a whole app: panels and windows), see "Context root" in the guide:
+/// ```rust,no_run
+/// use efx_core::doc_prelude::*;
+/// use efx::*;
+///
+/// let ctx = egui::Context::default();
+/// efx!(ctx: ctx, r#"
+///   <TopPanel id="menu"><Label>Menu</Label></TopPanel>
+///   <CentralPanel><Label>Content</Label></CentralPanel>
+/// "#);
+/// ```
+///
 /// # Errors
 /// - Unknown tag → `compile_error!`.
 /// - `Separator` with children → `compile_error!`.
@@ -60,6 +74,10 @@ pub fn efx(input: TokenStream) -> TokenStream {
         }
     };
 
+    if input.ctx {
+        return render::render_ctx_root(&ui, &ast).into();
+    }
+
     let expanded = if ast.len() == 1 {
         if let Node::Element(el) = &ast[0] {
             if el.name == "Button" {
@@ -87,3 +105,37 @@ pub fn efx(input: TokenStream) -> TokenStream {
 
     expanded.into()
 }
+
+/// Attribute macro `#[efx_component]` - turns a function into a custom tag for `efx!`.
+///
+/// The first parameter is the `Ui`, the other parameters become the props of the tag.
+/// The macro keeps the function and generates a `<Name>Props` struct with one field per prop;
+/// `<Name .../>` in a template builds that struct, so a missing or mistyped prop is a rustc error.
+/// # Example
+/// ```rust
+/// use efx_core::doc_prelude::*;
+/// use efx::*;
+///
+/// #[efx_component]
+/// fn Greeting(ui: &mut Ui, name: &str, times: usize) {
+///     for _ in 0..times {
+///         efx!(ui, "<Label>Hello, {name}!</Label>");
+///     }
+/// }
+///
+/// let mut ui = Ui::default();
+/// let user = String::from("Ferris");
+/// efx!(ui, r#"<Column><Greeting name={&user} times="2"/></Column>"#);
+/// ```
+///
+/// Parameters marked with `#[efx_slot]` (`impl FnOnce(&mut egui::Ui)`) take markup: the slot named
+/// `children` gets the children of the tag, other slots get `<Slot name="...">` children.
+///
+/// # Errors
+/// - A parameter that is not a plain identifier → compile error.
+/// - `impl Trait` parameter without `#[efx_slot]` → compile error, use a generic parameter instead.
+#[proc_macro_attribute]
+pub fn efx_component(args: TokenStream, item: TokenStream) -> TokenStream {
+    let item = parse_macro_input!(item as syn::ItemFn);
+    component::expand(args.into(), item).into()
+}
@@ -1,4 +1,6 @@
+use crate::component;
 use crate::tags::*;
+use crate::utils::shared::SharedAttributes;
 use efx_core::{Element, Node};
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
@@ -38,20 +40,82 @@ pub(crate) fn render_node_stmt<UI: ToTokens>(ui: &UI, node: &Node) -> TokenStrea
 fn render_element_stmt<UI: ToTokens>(ui: &UI, el: &Element) -> TokenStream {
     match el.name.as_str() {
         "CentralPanel" => render_tag::<CentralPanel>(ui, el),
+        "SidePanel" => render_tag::<SidePanel>(ui, el),
+        "TopPanel" | "BottomPanel" => render_tag::<TopBottomPanel>(ui, el),
+        "Window" => render_tag::<Window>(ui, el),
         "ScrollArea" => render_tag::<ScrollArea>(ui, el),
         "Row" => render_tag::<Row>(ui, el),
         "Column" => render_tag::<Column>(ui, el),
+        "Columns" => render_tag::<Columns>(ui, el),
+        "Col" => {
+            quote! { compile_error!("efx: <Col> must be placed inside <Columns>"); }
+        }
+        "Frame" | "Group" => render_tag::<Frame>(ui, el),
+        "Layout" | "Center" | "RightToLeft" => render_tag::<Layout>(ui, el),
         "Label" => render_tag::<Label>(ui, el),
+        "Heading" => render_tag::<Heading>(ui, el),
+        "Small" => render_tag::<Small>(ui, el),
+        "Code" => render_tag::<Code>(ui, el),
+        "Strong" => render_tag::<Strong>(ui, el),
+        "Weak" => render_tag::<Weak>(ui, el),
         "Button" => {
             let btn_expr = render_tag::<Button>(ui, el);
             quote! { #btn_expr; }
         }
         "Separator" => render_tag::<Separator>(ui, el),
+        "Space" => render_tag::<Space>(ui, el),
+        "Spacer" => render_tag::<Spacer>(ui, el),
         "Hyperlink" => {
             let ts = render_tag::<Hyperlink>(ui, el);
             quote! { #ts; }
         }
         "TextField" => render_tag::<TextField>(ui, el),
+        "Slider" => render_tag::<Slider>(ui, el),
+        "DragValue" => render_tag::<DragValue>(ui, el),
+        "ColorPicker" => render_tag::<ColorPicker>(ui, el),
+        "Image" => render_tag::<Image>(ui, el),
+        "ProgressBar" => render_tag::<ProgressBar>(ui, el),
+        "Spinner" => render_tag::<Spinner>(ui, el),
+        "ComboBox" | "Select" => render_tag::<ComboBox>(ui, el),
+        "Option" => {
+            quote! { compile_error!("efx: <Option> must be placed inside <ComboBox>"); }
+        }
+        "Collapsing" => render_tag::<Collapsing>(ui, el),
+        "Modal" => render_tag::<Modal>(ui, el),
+        "ModalActions" => {
+            quote! { compile_error!("efx: <ModalActions> must be placed inside <Modal>"); }
+        }
+        "MenuBar" => render_tag::<MenuBar>(ui, el),
+        "Menu" => render_tag::<Menu>(ui, el),
+        "MenuItem" | "MenuSeparator" => {
+            let msg = format!("efx: <{}> must be placed inside <Menu>", el.name);
+            quote! { compile_error!(#msg); }
+        }
+        "Tabs" => render_tag::<Tabs>(ui, el),
+        "Tab" => {
+            quote! { compile_error!("efx: <Tab> must be placed inside <Tabs>"); }
+        }
+        #[cfg(feature = "extras")]
+        "Table" => render_tag::<Table>(ui, el),
+        #[cfg(not(feature = "extras"))]
+        "Table" => {
+            quote! { compile_error!("efx: <Table> requires the `extras` feature of efx and `egui_extras` in dependencies"); }
+        }
+        "Rows" => {
+            quote! { compile_error!("efx: <Rows> must be placed inside <Table>"); }
+        }
+        "Grid" => render_tag::<Grid>(ui, el),
+        "GridRow" => {
+            quote! { compile_error!("efx: <GridRow> must be placed inside <Grid>"); }
+        }
+        "RadioGroup" => render_tag::<RadioGroup>(ui, el),
+        "Radio" => {
+            quote! { compile_error!("efx: <Radio> must be placed inside <RadioGroup>"); }
+        }
+        "Slot" => {
+            quote! { compile_error!("efx: <Slot> must be placed inside a component"); }
+        }
+        other if component::is_component_name(other) => component::render_component(ui, el),
         other => {
             let msg = format!("efx: unknown tag <{}>", other);
             quote! { compile_error!(#msg); }
@@ -59,9 +123,83 @@ fn render_element_stmt<UI: ToTokens>(ui: &UI, el: &Element) -> TokenStream {
     }
 }
 
+/// Root of `efx!(ctx: expr, ...)`: only panels and windows, shown on the `egui::Context`.
+/// Side/top/bottom panels go first in source order, then `<CentralPanel>` with the remaining space,
+/// then windows and modals on top.
+pub(crate) fn render_ctx_root<CTX: ToTokens>(ctx: &CTX, nodes: &[Node]) -> TokenStream {
+    let ctx_ts = quote!(__efx_ctx);
+    let mut panels = TokenStream::new();
+    let mut central = None;
+    let mut windows = TokenStream::new();
+
+    for n in nodes {
+        let el = match n {
+            Node::Text(t) if t.value.trim().is_empty() => continue,
+            Node::Element(el) => el,
+            _ => {
+                return quote! {
+                    compile_error!("efx: text cannot be placed at the root of a context template, put it into a panel or <Window>");
+                };
+            }
+        };
+
+        let rendered = match el.name.as_str() {
+            "SidePanel" => SidePanel::from_element(el).map(|t| t.render_with_ctx(&ctx_ts)),
+            "TopPanel" | "BottomPanel" => {
+                TopBottomPanel::from_element(el).map(|t| t.render_with_ctx(&ctx_ts))
+            }
+            "CentralPanel" => CentralPanel::from_element(el).map(|t| t.render_with_ctx(&ctx_ts)),
+            "Window" => Window::from_element(el).map(|t| t.render_with_ctx(&ctx_ts)),
+            "Modal" => Modal::from_element(el).map(|t| t.render_with_ctx(&ctx_ts)),
+            other => {
+                let msg = format!(
+                    "efx: <{}> cannot be placed at the root of a context template, \
+                     expected <TopPanel>, <BottomPanel>, <SidePanel>, <CentralPanel>, <Window> or <Modal>",
+                    other
+                );
+                return quote! { compile_error!(#msg); };
+            }
+        };
+        let rendered = match rendered {
+            Ok(ts) => ts,
+            Err(err) => return err,
+        };
+
+        match el.name.as_str() {
+            "CentralPanel" if central.is_some() => {
+                return quote! {
+                    compile_error!("efx: a context template may contain only one <CentralPanel>");
+                };
+            }
+            "CentralPanel" => central = Some(rendered),
+            "Window" | "Modal" => windows.extend(rendered),
+            _ => panels.extend(rendered),
+        }
+    }
+
+    quote! {{
+        let __efx_ctx: &egui::Context = &#ctx;
+        #panels
+        #central
+        #windows
+    }}
+}
+
 pub fn render_tag<T: Tag>(ui: &impl ToTokens, el: &Element) -> TokenStream {
-    match T::from_element(el) {
-        Ok(tag) => tag.render(ui),
+    if !T::SHARED_ATTRS {
+        return match T::from_element(el) {
+            Ok(tag) => tag.render(ui),
+            Err(err) => err,
+        };
+    }
+
+    let (el, shared) = match SharedAttributes::split(el, T::ATTR_NAMES) {
+        Ok(split) => split,
+        Err(err) => return err,
+    };
+    match T::from_element(&el) {
+        Ok(tag) if shared.is_empty() => tag.render(ui),
+        Ok(tag) => shared.wrap(ui, tag.render(&quote!(ui))),
         Err(err) => err,
     }
 }
@@ -1,6 +1,7 @@
 use crate::tags::{Tag, TagAttributes};
 use crate::utils::attr::*;
 use crate::utils::buffer::build_buffer_from_children;
+use crate::utils::expr::expr_opt;
 use efx_attrnames::AttrNames;
 use efx_core::Element;
 use proc_macro2::TokenStream;
@@ -33,8 +34,9 @@ impl Tag for Button {
         }
 
         if let Some(r) = self.attributes.rounding {
-            btn_build
-                .extend(quote!( __efx_btn = __efx_btn.rounding(egui::Rounding::same(#r as _)); ));
+            btn_build.extend(
+                quote!( __efx_btn = __efx_btn.corner_radius(egui::CornerRadius::same(#r as _)); ),
+            );
         }
 
         if self.attributes.min_width.is_some() || self.attributes.min_height.is_some() {
@@ -46,14 +48,11 @@ impl Tag for Button {
             );
         }
 
-        if let Some(b) = self.attributes.frame.clone() {
+        if let Some(b) = self.attributes.frame {
             btn_build.extend(quote!( __efx_btn = __efx_btn.frame(#b); ));
         }
 
-        let add_btn = match self.attributes.enabled.clone() {
-            Some(false) => quote!( let mut __efx_resp = #ui.add_enabled(false, __efx_btn); ),
-            _ => quote!( let mut __efx_resp = #ui.add(__efx_btn); ),
-        };
+        let add_btn = quote!( let __efx_resp = #ui.add(__efx_btn); );
 
         quote!( #btn_build #add_btn )
     }
@@ -61,6 +60,16 @@ impl Tag for Button {
     fn render<UI: ToTokens>(&self, ui: &UI) -> TokenStream {
         let (buf_init, buf_build) = build_buffer_from_children(&self.element.children);
 
+        // onClick: the handler runs on click, the Response is still the value of the tag
+        let click_apply = match &self.attributes.on_click {
+            Some(handler) => quote! {
+                if __efx_resp.clicked() {
+                    (#handler)();
+                }
+            },
+            None => quote!(),
+        };
+
         if self.attributes.is_plain_mode() {
             return quote! {{
                 #buf_init
@@ -74,33 +83,26 @@ impl Tag for Button {
 
         let content = self.content(ui);
 
-        // Tooltip
-        let tooltip_apply = if let Some(text) = self.attributes.tooltip.clone() {
-            quote!( __efx_resp = __efx_resp.on_hover_text(#text); )
-        } else {
-            quote!()
-        };
-
         quote! {{
             #buf_init
             #buf_build
             #rich_decl
             #content
-            #tooltip_apply
+            #click_apply
             __efx_resp
         }}
     }
 }
 
-#[derive(Clone, Debug, AttrNames)]
+#[derive(Clone, AttrNames)]
 struct Attributes {
     fill: Option<TokenStream>,
     min_width: Option<f32>,
     min_height: Option<f32>,
     frame: Option<bool>,
-    enabled: Option<bool>,
     rounding: Option<u8>,
-    tooltip: Option<String>,
+    #[attr(name = "onClick")]
+    on_click: Option<syn::Expr>,
 }
 
 impl Attributes {
@@ -111,25 +113,21 @@ impl Attributes {
             || self.fill.is_some()
             || self.rounding.is_some();
 
-        !has_style_attrs && self.enabled.is_none() && self.tooltip.is_none()
+        !has_style_attrs && self.on_click.is_none()
     }
 }
 
 impl TagAttributes for Attributes {
     fn new(el: &Element) -> Result<Self, TokenStream> {
-        let map = match attr_map(el, Attributes::ATTR_NAMES, "Button") {
-            Ok(m) => m,
-            Err(err) => return Err(err),
-        };
+        let map = attr_map(el, Attributes::ATTR_NAMES, "Button")?;
 
         Ok(Attributes {
             fill: color_tokens_opt(&map, "fill")?,
             min_width: f32_opt(&map, "min_width")?,
             min_height: f32_opt(&map, "min_height")?,
             frame: bool_opt(&map, "frame")?,
-            enabled: bool_opt(&map, "enabled")?,
             rounding: u8_opt(&map, "rounding")?,
-            tooltip: map.get("tooltip").map(|s| (*s).to_string()),
+            on_click: expr_opt(&map, "onClick")?,
         })
     }
 }
@@ -0,0 +1,116 @@
+use crate::tags::{Tag, TagAttributes};
+use crate::utils::attr::*;
+use crate::utils::buffer::build_buffer_from_el;
+use crate::utils::expr::{expr_opt, mut_ref_tokens};
+use crate::utils::render::{auto_id_salt, render_children_stmt};
+use efx_attrnames::AttrNames;
+use efx_core::Element;
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+
+pub struct Collapsing {
+    attributes: Attributes,
+    element: Element,
+}
+
+impl Tag for Collapsing {
+    const ATTR_NAMES: &'static [&'static str] = Attributes::ATTR_NAMES;
+
+    fn from_element(el: &Element) -> Result<Self, TokenStream>
+    where
+        Self: Sized,
+    {
+        let attributes = Attributes::new(el)?;
+        Ok(Self {
+            attributes,
+            element: el.clone(),
+        })
+    }
+
+    fn content<UI: ToTokens>(&self, _ui: &UI) -> TokenStream {
+        let a = &self.attributes;
+        let (title_init, title_build) = &a.title;
+        let id = match &a.id {
+            Some(id) => quote!(#id),
+            None => auto_id_salt(&self.element),
+        };
+        let mut build = quote! {
+            #title_init
+            #title_build
+            let mut __efx_ch = egui::CollapsingHeader::new(__efx_buf).id_salt(#id);
+        };
+
+        if let Some(b) = a.default_open {
+            build.extend(quote!( __efx_ch = __efx_ch.default_open(#b); ));
+        }
+        if let Some(b) = a.show_background {
+            build.extend(quote!( __efx_ch = __efx_ch.show_background(#b); ));
+        }
+        if let Some(icon) = &a.icon {
+            build.extend(quote!( __efx_ch = __efx_ch.icon(#icon); ));
+        }
+
+        build
+    }
+
+    fn render<UI: ToTokens>(&self, ui: &UI) -> TokenStream {
+        let children = render_children_stmt(&quote!(ui), &self.element.children);
+        let build = self.content(ui);
+
+        match &self.attributes.open {
+            // Two-way binding: the flag drives the header, a click on the header flips the flag
+            Some(open) => {
+                let open = mut_ref_tokens(open);
+                quote! {{
+                    #build
+                    let __efx_open: &mut bool = #open;
+                    let __efx_resp = __efx_ch.open(Some(*__efx_open)).show(#ui, |ui| { #children });
+                    if __efx_resp.header_response.clicked() {
+                        *__efx_open = !*__efx_open;
+                    }
+                }}
+            }
+            None => quote! {{
+                #build
+                __efx_ch.show(#ui, |ui| { #children });
+            }},
+        }
+    }
+}
+
+#[derive(Clone, AttrNames)]
+struct Attributes {
+    /// Title text with interpolations, `(init, build)` of `__efx_buf`.
+    title: (TokenStream, TokenStream),
+    id: Option<String>,
+    #[attr(name = "default-open")]
+    default_open: Option<bool>,
+    open: Option<syn::Expr>,
+    icon: Option<syn::Expr>,
+    #[attr(name = "show-background")]
+    show_background: Option<bool>,
+}
+
+impl TagAttributes for Attributes {
+    fn new(el: &Element) -> Result<Self, TokenStream> {
+        let map = attr_map(el, Attributes::ATTR_NAMES, "Collapsing")?;
+
+        let title = match build_buffer_from_el(el, "title")? {
+            Some(buf) => buf,
+            None => {
+                return Err(
+                    quote! { compile_error!("efx: <Collapsing> requires attribute `title`"); },
+                );
+            }
+        };
+
+        Ok(Attributes {
+            title,
+            id: text_opt(el, "id")?,
+            default_open: bool_opt(&map, "default-open")?,
+            open: expr_opt(&map, "open")?,
+            icon: expr_opt(&map, "icon")?,
+            show_background: bool_opt(&map, "show-background")?,
+        })
+    }
+}
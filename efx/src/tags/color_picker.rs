@@ -0,0 +1,81 @@
+use crate::tags::{Tag, TagAttributes};
+use crate::utils::attr::*;
+use crate::utils::expr::{expr_req, mut_ref_tokens};
+use efx_attrnames::AttrNames;
+use efx_core::Element;
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::Expr;
+
+/// <ColorPicker value={&mut color} [as="srgba|rgb|hsva"] [alpha="none|blend|premultiplied"]/>
+pub struct ColorPicker {
+    attributes: Attributes,
+}
+
+impl Tag for ColorPicker {
+    fn from_element(el: &Element) -> Result<Self, TokenStream>
+    where
+        Self: Sized,
+    {
+        if !el.children.is_empty() {
+            return Err(quote! {
+                compile_error!("efx: <ColorPicker/> must be self-closing without children");
+            });
+        }
+
+        let attributes = Attributes::new(el)?;
+        Ok(Self { attributes })
+    }
+
+    fn content<UI: ToTokens>(&self, ui: &UI) -> TokenStream {
+        let value = mut_ref_tokens(&self.attributes.value);
+        let alpha = match self.attributes.alpha {
+            Some(0) => quote!(egui::color_picker::Alpha::Opaque),
+            Some(2) => quote!(egui::color_picker::Alpha::BlendOrAdditive),
+            _ => quote!(egui::color_picker::Alpha::OnlyBlend),
+        };
+
+        match self.attributes.kind {
+            // [f32; 3], no alpha channel
+            Some(1) => quote!(egui::color_picker::color_edit_button_rgb(#ui, #value)),
+            Some(2) => quote!(egui::color_picker::color_edit_button_hsva(#ui, #value, #alpha)),
+            _ => quote!(egui::color_picker::color_edit_button_srgba(#ui, #value, #alpha)),
+        }
+    }
+
+    fn render<UI: ToTokens>(&self, ui: &UI) -> TokenStream {
+        let call = self.content(ui);
+
+        quote! {{
+            let _ = #call;
+        }}
+    }
+}
+
+#[derive(Clone, AttrNames)]
+struct Attributes {
+    value: Expr,
+    #[attr(name = "as")]
+    kind: Option<usize>,
+    alpha: Option<usize>,
+}
+
+impl TagAttributes for Attributes {
+    fn new(el: &Element) -> Result<Self, TokenStream> {
+        let map = attr_map(el, Attributes::ATTR_NAMES, "ColorPicker")?;
+
+        let kind = enum_opt(&map, "as", &["srgba", "rgb", "hsva"])?;
+        let alpha = enum_opt(&map, "alpha", &["none", "blend", "premultiplied"])?;
+        if kind == Some(1) && alpha.is_some() {
+            return Err(quote! {
+                compile_error!("efx: <ColorPicker as=\"rgb\"> has no alpha channel, remove attribute `alpha`");
+            });
+        }
+
+        Ok(Attributes {
+            value: expr_req(&map, "value", "ColorPicker")?,
+            kind,
+            alpha,
+        })
+    }
+}
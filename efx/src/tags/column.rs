@@ -2,9 +2,10 @@ use efx_core::Element;
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
 
-use crate::render::render_nodes_as_stmts;
 use crate::tags::{Block, TagAttributes};
 use crate::utils::attr::*;
+use crate::utils::flex::{is_flex, render_flex_children, JUSTIFY};
+use crate::utils::render::render_flow_children;
 use efx_attrnames::AttrNames;
 
 pub struct Column {
@@ -22,7 +23,15 @@ impl Block for Column {
     }
 
     fn content<UI: ToTokens>(&self, ui: &UI) -> TokenStream {
-        let body = render_nodes_as_stmts(&quote!(ui), &self.element.children);
+        let children = &self.element.children;
+        let body = if is_flex(self.attributes.justify, children) {
+            match render_flex_children(&self.element, false, self.attributes.justify) {
+                Ok(body) => body,
+                Err(err) => return err,
+            }
+        } else {
+            render_flow_children(&quote!(ui), children, false)
+        };
 
         // align: left|center|right → egui::Align::{Min,Center,Max} in Layout::top_down(...)
         if let Some(align) = &self.attributes.align {
@@ -79,19 +88,19 @@ struct Attributes {
     gap: Option<f32>,
     padding: Option<f32>,
     align: Option<String>,
+    /// Index into `JUSTIFY`.
+    justify: Option<usize>,
 }
 
 impl TagAttributes for Attributes {
     fn new(el: &Element) -> Result<Self, TokenStream> {
-        let map = match attr_map(el, Self::ATTR_NAMES, "Column") {
-            Ok(m) => m,
-            Err(err) => return Err(err),
-        };
+        let map = attr_map(el, Self::ATTR_NAMES, "Column")?;
 
         Ok(Attributes {
             gap: f32_opt(&map, "gap").unwrap_or(None),
             padding: f32_opt(&map, "padding").unwrap_or(None),
-            align: map.get("align").map(|s| (*s).to_string()),
+            align: text_opt(el, "align")?,
+            justify: enum_opt(&map, "justify", &JUSTIFY)?,
         })
     }
 }
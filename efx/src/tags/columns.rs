@@ -0,0 +1,129 @@
+use crate::tags::{Tag, TagAttributes};
+use crate::utils::attr::*;
+use crate::utils::render::render_children_stmt;
+use efx_attrnames::AttrNames;
+use efx_core::{Element, Node};
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+
+/// `<Columns count="N">`: equal-width columns via `ui.columns`.
+/// Children are either `<Col>` containers (one per column) or plain elements placed into the columns in turn.
+pub struct Columns {
+    attributes: Attributes,
+    /// Contents of every column.
+    columns: Vec<Vec<Node>>,
+}
+
+impl Tag for Columns {
+    fn from_element(el: &Element) -> Result<Self, TokenStream>
+    where
+        Self: Sized,
+    {
+        let attributes = Attributes::new(el)?;
+
+        let children: Vec<&Node> = el
+            .children
+            .iter()
+            .filter(|ch| !matches!(ch, Node::Text(t) if t.value.trim().is_empty()))
+            .collect();
+        if children.is_empty() {
+            return Err(quote! { compile_error!("efx: <Columns> requires children"); });
+        }
+        let is_col = |ch: &&Node| matches!(ch, Node::Element(c) if c.name == "Col");
+
+        let columns = if children.iter().any(is_col) {
+            if !children.iter().all(is_col) {
+                return Err(quote! {
+                    compile_error!("efx: <Columns> children must be either all <Col> or no <Col> at all");
+                });
+            }
+            let mut columns = Vec::new();
+            for ch in children {
+                if let Node::Element(col) = ch {
+                    attr_map(col, &[], "Col")?;
+                    columns.push(col.children.clone());
+                }
+            }
+            if let Some(n) = attributes.count {
+                if n != columns.len() {
+                    let msg = format!(
+                        "efx: <Columns count=\"{}\"> has {} <Col> children",
+                        n,
+                        columns.len()
+                    );
+                    return Err(quote! { compile_error!(#msg); });
+                }
+            }
+            columns
+        } else {
+            // Child `i` goes into column `i % count`
+            let count = attributes.count.unwrap_or(children.len());
+            let mut columns = vec![Vec::new(); count];
+            for (i, ch) in children.into_iter().enumerate() {
+                columns[i % count].push(ch.clone());
+            }
+            columns
+        };
+
+        Ok(Self {
+            attributes,
+            columns,
+        })
+    }
+
+    fn content<UI: ToTokens>(&self, _ui: &UI) -> TokenStream {
+        let mut out = TokenStream::new();
+        for (i, nodes) in self.columns.iter().enumerate() {
+            if nodes.is_empty() {
+                continue;
+            }
+            // Children keep the item spacing of the parent, `gap` only separates the columns
+            let restore = if self.attributes.gap.is_some() {
+                quote!( ui.spacing_mut().item_spacing.x = __efx_old_gap; )
+            } else {
+                quote!()
+            };
+            let body = render_children_stmt(&quote!(ui), nodes);
+            out.extend(quote! {{
+                let ui = &mut __efx_cols[#i];
+                #restore
+                #body
+            }});
+        }
+        out
+    }
+
+    fn render<UI: ToTokens>(&self, ui: &UI) -> TokenStream {
+        let count = self.columns.len();
+        let columns = self.content(ui);
+
+        match self.attributes.gap {
+            Some(gap) => quote! {{
+                let __efx_old_gap = #ui.spacing().item_spacing.x;
+                #ui.spacing_mut().item_spacing.x = #gap as f32;
+                #ui.columns(#count, |__efx_cols| { #columns });
+                #ui.spacing_mut().item_spacing.x = __efx_old_gap;
+            }},
+            None => quote! {
+                #ui.columns(#count, |__efx_cols| { #columns });
+            },
+        }
+    }
+}
+
+#[derive(Clone, Debug, AttrNames)]
+struct Attributes {
+    count: Option<usize>,
+    gap: Option<f32>,
+}
+
+impl TagAttributes for Attributes {
+    fn new(el: &Element) -> Result<Self, TokenStream> {
+        let map = attr_map(el, Attributes::ATTR_NAMES, "Columns")?;
+
+        Ok(Attributes {
+            count: count_opt(&map, "count")?,
+            gap: f32_opt(&map, "gap")?,
+        })
+    }
+}
@@ -0,0 +1,240 @@
+use crate::tags::{Tag, TagAttributes};
+use crate::utils::attr::*;
+use crate::utils::buffer::build_buffer_from_children;
+use crate::utils::expr::{expr_opt, expr_req, mut_ref_tokens};
+use crate::utils::render::child_elements;
+use crate::utils::shared::{avail_tokens, size_opt, Size};
+use efx_attrnames::AttrNames;
+use efx_core::Element;
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::{Expr, Ident};
+
+/// <ComboBox selected={&mut state.choice} [id=..] [label=..] [width=..]>
+///     <Option value={Choice::A}>A</Option>
+/// </ComboBox>
+///
+/// Dynamic lists: `<ComboBox selected={..} options={iter} [as="item"] [display={expr}]/>`.
+/// `<Select>` is an alias.
+pub struct ComboBox {
+    attributes: Attributes,
+    options: Vec<ComboOption>,
+}
+
+impl Tag for ComboBox {
+    const ATTR_NAMES: &'static [&'static str] = Attributes::ATTR_NAMES;
+
+    fn from_element(el: &Element) -> Result<Self, TokenStream>
+    where
+        Self: Sized,
+    {
+        let attributes = Attributes::new(el)?;
+        let options = child_elements(&el.children, "ComboBox", &["Option"])?
+            .into_iter()
+            .map(ComboOption::from_element)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if attributes.options.is_some() && !options.is_empty() {
+            return Err(quote! {
+                compile_error!("efx: <ComboBox> takes either `options` or <Option> children, not both");
+            });
+        }
+
+        Ok(Self {
+            attributes,
+            options,
+        })
+    }
+
+    fn content<UI: ToTokens>(&self, ui: &UI) -> TokenStream {
+        let mut out = TokenStream::new();
+
+        if let Some(iter) = &self.attributes.options {
+            let item = &self.attributes.binding;
+            let display = self.display();
+            out.extend(quote! {
+                for #item in #iter {
+                    let __efx_text = ::std::format!("{}", #display);
+                    #ui.selectable_value(&mut *__efx_combo_current, #item, __efx_text);
+                }
+            });
+        }
+
+        for opt in &self.options {
+            out.extend(opt.render(ui));
+        }
+
+        out
+    }
+
+    fn render<UI: ToTokens>(&self, ui: &UI) -> TokenStream {
+        let current = mut_ref_tokens(&self.attributes.selected);
+        let selected_text = self.selected_text();
+        let items = self.content(&quote!(ui));
+
+        let ctor = match (&self.attributes.id, &self.attributes.label) {
+            (Some(id), Some(label)) => quote!( egui::ComboBox::new(#id, #label) ),
+            (Some(id), None) => quote!( egui::ComboBox::from_id_salt(#id) ),
+            (None, Some(label)) => quote!( egui::ComboBox::from_label(#label) ),
+            (None, None) => {
+                return quote! { compile_error!("efx: <ComboBox> requires `id` or `label` attribute"); };
+            }
+        };
+
+        let mut build = avail_tokens(ui, &[self.attributes.width, self.attributes.height]);
+        build.extend(quote!( let mut __efx_combo = #ctor; ));
+        if let Some(w) = self.attributes.width {
+            let w = w.tokens(quote!(x));
+            build.extend(quote!( __efx_combo = __efx_combo.width(#w); ));
+        }
+        if let Some(h) = self.attributes.height {
+            let h = h.tokens(quote!(y));
+            build.extend(quote!( __efx_combo = __efx_combo.height(#h); ));
+        }
+
+        quote! {{
+            let __efx_combo_current = #current;
+            let __efx_combo_text = #selected_text;
+            #build
+            __efx_combo
+                .selected_text(__efx_combo_text)
+                .show_ui(#ui, |ui| { #items });
+        }}
+    }
+}
+
+impl ComboBox {
+    /// Display expression for `options` items (the item itself by default).
+    fn display(&self) -> TokenStream {
+        match &self.attributes.display {
+            Some(expr) => quote!( #expr ),
+            None => {
+                let item = &self.attributes.binding;
+                quote!( #item )
+            }
+        }
+    }
+
+    /// Text of the closed combo box: the label of the option equal to the current value.
+    fn selected_text(&self) -> TokenStream {
+        if self.attributes.options.is_some() {
+            let item = &self.attributes.binding;
+            let display = self.display();
+            // Same binding type as in the option loop, so `display` sees the item by value there and here
+            return quote! {{
+                let #item = ::core::clone::Clone::clone(&*__efx_combo_current);
+                ::std::format!("{}", #display)
+            }};
+        }
+
+        let mut arms = TokenStream::new();
+        for opt in &self.options {
+            let value = &opt.attributes.value;
+            let (buf_init, buf_build) = build_buffer_from_children(&opt.element.children);
+            arms.extend(quote! {
+                if *__efx_combo_current == (#value) {
+                    #buf_init
+                    #buf_build
+                    __efx_selected = __efx_buf;
+                }
+            });
+        }
+
+        quote! {{
+            let mut __efx_selected = ::std::string::String::new();
+            #arms
+            __efx_selected
+        }}
+    }
+}
+
+/// Static option of a `ComboBox`: `<Option value={expr}>text</Option>`.
+struct ComboOption {
+    attributes: OptionAttributes,
+    element: Element,
+}
+
+impl ComboOption {
+    fn from_element(el: &Element) -> Result<Self, TokenStream> {
+        let attributes = OptionAttributes::new(el)?;
+        Ok(Self {
+            attributes,
+            element: el.clone(),
+        })
+    }
+
+    fn render<UI: ToTokens>(&self, ui: &UI) -> TokenStream {
+        let value = &self.attributes.value;
+        let (buf_init, buf_build) = build_buffer_from_children(&self.element.children);
+
+        quote! {{
+            #buf_init
+            #buf_build
+            #ui.selectable_value(&mut *__efx_combo_current, #value, __efx_buf);
+        }}
+    }
+}
+
+#[derive(Clone, AttrNames)]
+struct Attributes {
+    selected: Expr,
+    id: Option<String>,
+    label: Option<String>,
+    width: Option<Size>,
+    height: Option<Size>,
+    options: Option<Expr>,
+    #[attr(name = "as")]
+    binding: Ident,
+    display: Option<Expr>,
+}
+
+impl TagAttributes for Attributes {
+    fn new(el: &Element) -> Result<Self, TokenStream> {
+        let map = attr_map(el, Attributes::ATTR_NAMES, "ComboBox")?;
+
+        for key in ["as", "display"] {
+            if map.contains_key(key) && !map.contains_key("options") {
+                let msg = format!("efx: attribute `{}` requires `options`", key);
+                return Err(quote! { compile_error!(#msg); });
+            }
+        }
+
+        let binding_src = map.get("as").copied().unwrap_or("item");
+        let binding = match syn::parse_str::<Ident>(binding_src) {
+            Ok(id) => id,
+            Err(_) => {
+                let msg = format!(
+                    "efx: <ComboBox> attribute `as` must be an identifier, got `{}`",
+                    binding_src
+                );
+                return Err(quote! { compile_error!(#msg); });
+            }
+        };
+
+        Ok(Attributes {
+            selected: expr_req(&map, "selected", "ComboBox")?,
+            id: text_opt(el, "id")?,
+            label: text_opt(el, "label")?,
+            width: size_opt(&map, "width")?,
+            height: size_opt(&map, "height")?,
+            options: expr_opt(&map, "options")?,
+            binding,
+            display: expr_opt(&map, "display")?,
+        })
+    }
+}
+
+#[derive(Clone, AttrNames)]
+struct OptionAttributes {
+    value: Expr,
+}
+
+impl TagAttributes for OptionAttributes {
+    fn new(el: &Element) -> Result<Self, TokenStream> {
+        let map = attr_map(el, OptionAttributes::ATTR_NAMES, "Option")?;
+
+        Ok(OptionAttributes {
+            value: expr_req(&map, "value", "Option")?,
+        })
+    }
+}
@@ -0,0 +1,86 @@
+use crate::tags::{Tag, TagAttributes};
+use crate::utils::attr::*;
+use crate::utils::expr::{expr_req, mut_ref_tokens};
+use efx_attrnames::AttrNames;
+use efx_core::Element;
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::Expr;
+
+/// <DragValue value={&mut x} [speed=..] [range="0..=10"] [prefix=..] [suffix=..] [decimals=..]/>
+pub struct DragValue {
+    attributes: Attributes,
+}
+
+impl Tag for DragValue {
+    fn from_element(el: &Element) -> Result<Self, TokenStream>
+    where
+        Self: Sized,
+    {
+        if !el.children.is_empty() {
+            return Err(quote! {
+                compile_error!("efx: <DragValue/> must be self-closing without children");
+            });
+        }
+
+        let attributes = Attributes::new(el)?;
+        Ok(Self { attributes })
+    }
+
+    fn content<UI: ToTokens>(&self, _ui: &UI) -> TokenStream {
+        let value = mut_ref_tokens(&self.attributes.value);
+        let mut build = quote!( let mut __efx_dv = egui::DragValue::new(#value); );
+
+        if let Some(speed) = self.attributes.speed {
+            build.extend(quote!( __efx_dv = __efx_dv.speed(#speed); ));
+        }
+        if let Some((min, max)) = self.attributes.range {
+            build.extend(quote!( __efx_dv = __efx_dv.range(#min..=#max); ));
+        }
+        if let Some(p) = &self.attributes.prefix {
+            build.extend(quote!( __efx_dv = __efx_dv.prefix(#p); ));
+        }
+        if let Some(s) = &self.attributes.suffix {
+            build.extend(quote!( __efx_dv = __efx_dv.suffix(#s); ));
+        }
+        if let Some(d) = self.attributes.decimals {
+            build.extend(quote!( __efx_dv = __efx_dv.fixed_decimals(#d as usize); ));
+        }
+
+        build
+    }
+
+    fn render<UI: ToTokens>(&self, ui: &UI) -> TokenStream {
+        let build = self.content(ui);
+
+        quote! {{
+            #build
+            let _ = #ui.add(__efx_dv);
+        }}
+    }
+}
+
+#[derive(Clone, AttrNames)]
+struct Attributes {
+    value: Expr,
+    speed: Option<f64>,
+    range: Option<(f64, f64)>,
+    prefix: Option<String>,
+    suffix: Option<String>,
+    decimals: Option<u8>,
+}
+
+impl TagAttributes for Attributes {
+    fn new(el: &Element) -> Result<Self, TokenStream> {
+        let map = attr_map(el, Attributes::ATTR_NAMES, "DragValue")?;
+
+        Ok(Attributes {
+            value: expr_req(&map, "value", "DragValue")?,
+            speed: f64_opt(&map, "speed")?,
+            range: range_opt(&map, "range")?,
+            prefix: text_opt(el, "prefix")?,
+            suffix: text_opt(el, "suffix")?,
+            decimals: u8_opt(&map, "decimals")?,
+        })
+    }
+}
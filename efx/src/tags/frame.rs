@@ -0,0 +1,76 @@
+use crate::tags::{Tag, TagAttributes};
+use crate::utils::attr::*;
+use crate::utils::frame::FrameAttributes;
+use crate::utils::render::render_children_stmt;
+use efx_attrnames::AttrNames;
+use efx_core::Element;
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+
+/// `<Frame>` and `<Group>`: a styled box around children, backed by `egui::Frame`.
+/// `<Group>` starts from the `ui.group` look, `<Frame>` from an empty frame.
+pub struct Frame {
+    group: bool,
+    attributes: Attributes,
+    element: Element,
+}
+
+impl Tag for Frame {
+    fn from_element(el: &Element) -> Result<Self, TokenStream>
+    where
+        Self: Sized,
+    {
+        let attributes = Attributes::new(el)?;
+        Ok(Self {
+            group: el.name == "Group",
+            attributes,
+            element: el.clone(),
+        })
+    }
+
+    fn content<UI: ToTokens>(&self, ui: &UI) -> TokenStream {
+        let base = if self.group {
+            quote!(egui::Frame::group(#ui.style()))
+        } else {
+            quote!(egui::Frame::new())
+        };
+        self.attributes.frame.build(base)
+    }
+
+    fn render<UI: ToTokens>(&self, ui: &UI) -> TokenStream {
+        let frame_build = self.content(ui);
+        let children = render_children_stmt(&quote!(ui), &self.element.children);
+        let width = match self.attributes.inner_width {
+            Some(w) => quote!( ui.set_width(#w as f32); ),
+            None => quote!(),
+        };
+
+        quote! {{
+            #frame_build
+            __efx_frame.show(#ui, |ui| {
+                #width
+                #children
+            });
+        }}
+    }
+}
+
+#[derive(Clone, Debug, AttrNames)]
+struct Attributes {
+    #[attr(name = "inner-width")]
+    inner_width: Option<f32>,
+    #[attr(skip)]
+    frame: FrameAttributes,
+}
+
+impl TagAttributes for Attributes {
+    fn new(el: &Element) -> Result<Self, TokenStream> {
+        let known = [Attributes::ATTR_NAMES, FrameAttributes::ATTR_NAMES].concat();
+        let map = attr_map(el, &known, el.name.as_str())?;
+
+        Ok(Attributes {
+            inner_width: f32_opt(&map, "inner-width")?,
+            frame: FrameAttributes::from_map(&map)?,
+        })
+    }
+}
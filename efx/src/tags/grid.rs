@@ -0,0 +1,146 @@
+use crate::render::render_node_stmt;
+use crate::tags::{Tag, TagAttributes};
+use crate::utils::attr::*;
+use crate::utils::render::{child_elements, render_children_stmt};
+use efx_attrnames::AttrNames;
+use efx_core::{Element, Node};
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+
+pub struct Grid {
+    attributes: Attributes,
+    rows: Vec<Element>,
+    element: Element,
+}
+
+impl Tag for Grid {
+    const ATTR_NAMES: &'static [&'static str] = Attributes::ATTR_NAMES;
+
+    fn from_element(el: &Element) -> Result<Self, TokenStream>
+    where
+        Self: Sized,
+    {
+        let attributes = Attributes::new(el)?;
+
+        // Explicit rows: once a <GridRow> is used, every child must be a row.
+        let has_rows = el
+            .children
+            .iter()
+            .any(|ch| matches!(ch, Node::Element(e) if e.name == "GridRow"));
+        let rows = if has_rows {
+            let rows = child_elements(&el.children, "Grid", &["GridRow"])?;
+            for row in &rows {
+                attr_map(row, &[], "GridRow")?;
+            }
+            rows.into_iter().cloned().collect()
+        } else {
+            Vec::new()
+        };
+
+        Ok(Self {
+            attributes,
+            rows,
+            element: el.clone(),
+        })
+    }
+
+    fn content<UI: ToTokens>(&self, _ui: &UI) -> TokenStream {
+        let a = &self.attributes;
+        let id = &a.id;
+        let mut build = quote!( let mut __efx_grid = egui::Grid::new(#id); );
+
+        if let Some(n) = a.columns {
+            build.extend(quote!( __efx_grid = __efx_grid.num_columns(#n); ));
+        }
+        if let Some(b) = a.striped {
+            build.extend(quote!( __efx_grid = __efx_grid.striped(#b); ));
+        }
+        if let Some((x, y)) = a.spacing {
+            build.extend(
+                quote!( __efx_grid = __efx_grid.spacing(egui::vec2(#x as f32, #y as f32)); ),
+            );
+        }
+        if let Some(w) = a.min_col_width {
+            build.extend(quote!( __efx_grid = __efx_grid.min_col_width(#w as f32); ));
+        }
+        if let Some(h) = a.min_row_height {
+            build.extend(quote!( __efx_grid = __efx_grid.min_row_height(#h as f32); ));
+        }
+
+        build
+    }
+
+    fn render<UI: ToTokens>(&self, ui: &UI) -> TokenStream {
+        let build = self.content(ui);
+        let body = self.body();
+
+        quote! {{
+            #build
+            __efx_grid.show(#ui, |ui| { #body });
+        }}
+    }
+}
+
+impl Grid {
+    /// Grid cells with `ui.end_row()` after every `<GridRow>`, or after every `columns` children.
+    fn body(&self) -> TokenStream {
+        let mut out = TokenStream::new();
+
+        if !self.rows.is_empty() {
+            for row in &self.rows {
+                let cells = render_children_stmt(&quote!(ui), &row.children);
+                out.extend(quote! { #cells ui.end_row(); });
+            }
+            return out;
+        }
+
+        let cells = self.element.children.iter().filter(|ch| match ch {
+            Node::Text(t) => !t.value.trim().is_empty(),
+            _ => true,
+        });
+        for (i, ch) in cells.enumerate() {
+            out.extend(render_node_stmt(&quote!(ui), ch));
+            if let Some(n) = self.attributes.columns {
+                if (i + 1) % n == 0 {
+                    out.extend(quote! { ui.end_row(); });
+                }
+            }
+        }
+        out
+    }
+}
+
+#[derive(Clone, Debug, AttrNames)]
+struct Attributes {
+    id: String,
+    columns: Option<usize>,
+    striped: Option<bool>,
+    /// `"X Y"` or a single value for both.
+    spacing: Option<(f32, f32)>,
+    #[attr(name = "min-col-width")]
+    min_col_width: Option<f32>,
+    #[attr(name = "min-row-height")]
+    min_row_height: Option<f32>,
+}
+
+impl TagAttributes for Attributes {
+    fn new(el: &Element) -> Result<Self, TokenStream> {
+        let map = attr_map(el, Attributes::ATTR_NAMES, "Grid")?;
+
+        let id = match text_opt(el, "id")? {
+            Some(id) => id,
+            None => {
+                return Err(quote! { compile_error!("efx: <Grid> requires attribute `id`"); });
+            }
+        };
+
+        Ok(Attributes {
+            id,
+            columns: count_opt(&map, "columns")?,
+            striped: bool_opt(&map, "striped")?,
+            spacing: vec2_opt(&map, "spacing")?,
+            min_col_width: f32_opt(&map, "min-col-width")?,
+            min_row_height: f32_opt(&map, "min-row-height")?,
+        })
+    }
+}
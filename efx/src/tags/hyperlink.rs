@@ -6,7 +6,7 @@ use efx_core::Element;
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
 
-/// <Hyperlink url="..." [open_external=bool] [color=...] [underline=bool] [tooltip=...]>text?</Hyperlink>
+/// <Hyperlink url="..." [open_external=bool] [color=...] [underline=bool]>text?</Hyperlink>
 pub struct Hyperlink {
     attributes: Attributes,
     element: Element,
@@ -72,19 +72,11 @@ impl Tag for Hyperlink {
             None => quote!(),
         };
 
-        let tooltip_ts = if let Some(t) = &self.attributes.tooltip {
-            quote!( __efx_resp = __efx_resp.on_hover_text(#t); )
-        } else {
-            quote!()
-        };
-
         quote! {{
             let __efx_label = #label_logic;
             let __efx_rich = egui::RichText::new(__efx_label) #rich_mods ;
             let __efx_link = egui::widgets::Hyperlink::from_label_and_url(__efx_rich, #url) #open_tab_ts ;
-            let mut __efx_resp = #ui.add(__efx_link);
-            #tooltip_ts
-            __efx_resp
+            #ui.add(__efx_link)
         }}
     }
 }
@@ -96,27 +88,20 @@ struct Attributes {
     underline: Option<bool>,
     #[attr(name = "color")]
     color_ts: Option<TokenStream>,
-    tooltip: Option<String>,
 }
 
 impl Attributes {
-    pub(crate) fn has_style_or_behavior(self: Self) -> bool {
-        self.open_external.is_some()
-            || self.underline.is_some()
-            || self.color_ts.is_some()
-            || self.tooltip.is_some()
+    pub(crate) fn has_style_or_behavior(self) -> bool {
+        self.open_external.is_some() || self.underline.is_some() || self.color_ts.is_some()
     }
 }
 
 impl TagAttributes for Attributes {
     fn new(el: &Element) -> Result<Self, TokenStream> {
-        let map = match attr_map(el, Attributes::ATTR_NAMES, "Hyperlink") {
-            Ok(m) => m,
-            Err(err) => return Err(err),
-        };
+        let map = attr_map(el, Attributes::ATTR_NAMES, "Hyperlink")?;
 
-        let url = match map.get("url") {
-            Some(u) if !u.is_empty() => (*u).to_string(),
+        let url = match text_opt(el, "url")? {
+            Some(u) if !u.is_empty() => u,
             _ => return Err(quote! { compile_error!("efx: <Hyperlink> requires `url=\"...\"`"); }),
         };
 
@@ -125,7 +110,6 @@ impl TagAttributes for Attributes {
             open_external: bool_opt(&map, "open_external").unwrap_or(None), // web: .open_in_new_tab
             underline: bool_opt(&map, "underline")?,
             color_ts: color_tokens_opt(&map, "color").unwrap_or(None),
-            tooltip: map.get("tooltip").map(|s| (*s).to_string()),
         })
     }
 }
@@ -0,0 +1,193 @@
+use crate::tags::{Tag, TagAttributes};
+use crate::utils::attr::*;
+use crate::utils::expr::expr_opt;
+use crate::utils::shared::{avail_tokens, size_opt, Size};
+use efx_attrnames::AttrNames;
+use efx_core::Element;
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::Expr;
+
+/// <Image src="file://..." | texture={id} | src="bytes://..." bytes={..}
+///        [width=..] [height=..] [max-width=..] [fit="contain|cover|original"]
+///        [rounding=..] [tint=..] [alt=..] [onClick={|| ..}]/>
+pub struct Image {
+    attributes: Attributes,
+    source: TokenStream,
+}
+
+impl Tag for Image {
+    const ATTR_NAMES: &'static [&'static str] = Attributes::ATTR_NAMES;
+
+    fn from_element(el: &Element) -> Result<Self, TokenStream>
+    where
+        Self: Sized,
+    {
+        if !el.children.is_empty() {
+            return Err(quote! {
+                compile_error!("efx: <Image/> must be self-closing without children");
+            });
+        }
+
+        let attributes = Attributes::new(el)?;
+        if attributes.fit == Some(1) && (attributes.width.is_none() || attributes.height.is_none())
+        {
+            return Err(quote! {
+                compile_error!("efx: <Image fit=\"cover\"> requires both `width` and `height`");
+            });
+        }
+        let source = attributes.source()?;
+        Ok(Self { attributes, source })
+    }
+
+    fn content<UI: ToTokens>(&self, ui: &UI) -> TokenStream {
+        let a = &self.attributes;
+        let source = &self.source;
+        let mut build = avail_tokens(ui, &[a.width, a.height, a.max_width]);
+        build.extend(quote!( let mut __efx_img = #source; ));
+
+        match (a.fit, a.width, a.height) {
+            // original
+            (Some(2), _, _) => {
+                build.extend(quote!( __efx_img = __efx_img.fit_to_original_size(1.0); ))
+            }
+            // cover: scaled to fill the whole box keeping the aspect ratio, the overflow is cropped
+            // through the uv rect (the image size is known once the texture is loaded)
+            (Some(1), Some(w), Some(h)) => {
+                let (w, h) = (w.tokens(quote!(x)), h.tokens(quote!(y)));
+                build.extend(quote! {
+                    let __efx_box = egui::vec2(#w, #h);
+                    if let Some(__efx_size) = __efx_img
+                        .load_for_size(#ui.ctx(), __efx_box)
+                        .ok()
+                        .and_then(|poll| poll.size())
+                        .filter(|size| size.x > 0.0 && size.y > 0.0)
+                    {
+                        let __efx_scale = (__efx_box.x / __efx_size.x).max(__efx_box.y / __efx_size.y);
+                        let __efx_part = __efx_box / (__efx_size * __efx_scale);
+                        __efx_img = __efx_img.uv(egui::Rect::from_center_size(egui::pos2(0.5, 0.5), __efx_part));
+                    }
+                    __efx_img = __efx_img.maintain_aspect_ratio(false);
+                })
+            }
+            // contain (egui default)
+            _ => {}
+        }
+
+        if a.fit != Some(2) && (a.width.is_some() || a.height.is_some()) {
+            let w = a
+                .width
+                .map_or(quote!(f32::INFINITY), |w| w.tokens(quote!(x)));
+            let h = a
+                .height
+                .map_or(quote!(f32::INFINITY), |h| h.tokens(quote!(y)));
+            build.extend(quote!( __efx_img = __efx_img.fit_to_exact_size(egui::vec2(#w, #h)); ));
+        }
+        if let Some(w) = a.max_width {
+            let w = w.tokens(quote!(x));
+            build.extend(quote!( __efx_img = __efx_img.max_width(#w); ));
+        }
+        if let Some(r) = a.rounding {
+            build.extend(
+                quote!( __efx_img = __efx_img.corner_radius(egui::CornerRadius::same(#r as _)); ),
+            );
+        }
+        if let Some(ts) = &a.tint {
+            build.extend(quote!( __efx_img = __efx_img.tint(#ts); ));
+        }
+        if let Some(alt) = &a.alt {
+            build.extend(quote!( __efx_img = __efx_img.alt_text(#alt); ));
+        }
+        if a.on_click.is_some() {
+            build.extend(quote!( __efx_img = __efx_img.sense(egui::Sense::click()); ));
+        }
+
+        build
+    }
+
+    fn render<UI: ToTokens>(&self, ui: &UI) -> TokenStream {
+        let build = self.content(ui);
+
+        let on_click = match &self.attributes.on_click {
+            Some(handler) => quote! {
+                if __efx_resp.clicked() {
+                    (#handler)();
+                }
+            },
+            None => quote!( let _ = __efx_resp; ),
+        };
+
+        quote! {{
+            #build
+            let __efx_resp = #ui.add(__efx_img);
+            #on_click
+        }}
+    }
+}
+
+#[derive(Clone, AttrNames)]
+struct Attributes {
+    src: Option<String>,
+    texture: Option<Expr>,
+    bytes: Option<Expr>,
+    width: Option<Size>,
+    height: Option<Size>,
+    #[attr(name = "max-width")]
+    max_width: Option<Size>,
+    fit: Option<usize>,
+    rounding: Option<u8>,
+    tint: Option<TokenStream>,
+    alt: Option<String>,
+    #[attr(name = "onClick")]
+    on_click: Option<Expr>,
+}
+
+impl Attributes {
+    /// `egui::Image` constructor for the given source; exactly one source is allowed.
+    fn source(&self) -> Result<TokenStream, TokenStream> {
+        Ok(match (&self.src, &self.texture, &self.bytes) {
+            (Some(uri), None, Some(bytes)) => quote!( egui::Image::from_bytes(#uri, #bytes) ),
+            (Some(uri), None, None) => quote!( egui::Image::from_uri(#uri) ),
+            (None, Some(tex), None) => match (self.width, self.height) {
+                // A bare TextureId has no size of its own; `cover` needs the real size to crop
+                (Some(w), Some(h)) if self.fit != Some(1) => {
+                    let (w, h) = (w.tokens(quote!(x)), h.tokens(quote!(y)));
+                    quote! {
+                        egui::Image::from_texture(egui::load::SizedTexture::new(#tex, egui::vec2(#w, #h)))
+                    }
+                }
+                _ => quote!( egui::Image::from_texture(#tex) ),
+            },
+            (None, None, Some(_)) => {
+                return Err(quote! {
+                    compile_error!("efx: <Image> with `bytes` requires `src` as the cache uri, e.g. src=\"bytes://logo.png\"");
+                });
+            }
+            _ => {
+                return Err(quote! {
+                    compile_error!("efx: <Image> requires exactly one source: `src`, `texture` or `bytes`");
+                });
+            }
+        })
+    }
+}
+
+impl TagAttributes for Attributes {
+    fn new(el: &Element) -> Result<Self, TokenStream> {
+        let map = attr_map(el, Attributes::ATTR_NAMES, "Image")?;
+
+        Ok(Attributes {
+            src: text_opt(el, "src")?,
+            texture: expr_opt(&map, "texture")?,
+            bytes: expr_opt(&map, "bytes")?,
+            width: size_opt(&map, "width")?,
+            height: size_opt(&map, "height")?,
+            max_width: size_opt(&map, "max-width")?,
+            fit: enum_opt(&map, "fit", &["contain", "cover", "original"])?,
+            rounding: u8_opt(&map, "rounding")?,
+            tint: color_tokens_opt(&map, "tint")?,
+            alt: text_opt(el, "alt")?,
+            on_click: expr_opt(&map, "onClick")?,
+        })
+    }
+}
@@ -9,6 +9,8 @@ use quote::{quote, ToTokens};
 pub struct Label {
     attributes: Attributes,
     element: Element,
+    /// Base `RichText` modifiers applied before the attribute ones (semantic text tags).
+    style: TokenStream,
 }
 
 impl Tag for Label {
@@ -16,18 +18,14 @@ impl Tag for Label {
     where
         Self: Sized,
     {
-        let attributes = Attributes::new(el)?;
-        Ok(Self {
-            attributes,
-            element: el.clone(),
-        })
+        Self::styled(el, "Label", TokenStream::new())
     }
 
     fn content<UI: ToTokens>(&self, ui: &UI) -> TokenStream {
         match &self.attributes.wrap {
             Some(true) => {
                 quote! {
-                    let __efx_widget = egui::widgets::Label::new(__efx_rich).wrap(true);
+                    let __efx_widget = egui::widgets::Label::new(__efx_rich).wrap();
                     #ui.add(__efx_widget);
                 }
             }
@@ -70,8 +68,19 @@ impl Tag for Label {
 }
 
 impl Label {
+    /// Label with a fixed base style, e.g. `.code()` for `<Code>`.
+    /// Attributes are checked against the Label ones and reported under `tag`.
+    pub(crate) fn styled(el: &Element, tag: &str, style: TokenStream) -> Result<Self, TokenStream> {
+        let attributes = Attributes::parse(el, tag)?;
+        Ok(Self {
+            attributes,
+            element: el.clone(),
+            style,
+        })
+    }
+
     fn set_mods(&self) -> TokenStream {
-        let mut mods = TokenStream::new();
+        let mut mods = self.style.clone();
 
         if let Some(ts) = &self.attributes.color {
             mods.extend(quote! { .color(#ts) });
@@ -119,10 +128,13 @@ struct Attributes {
 
 impl TagAttributes for Attributes {
     fn new(el: &Element) -> Result<Self, TokenStream> {
-        let map = match attr_map(el, Self::ATTR_NAMES, "Label") {
-            Ok(m) => m,
-            Err(err) => return Err(err),
-        };
+        Self::parse(el, "Label")
+    }
+}
+
+impl Attributes {
+    fn parse(el: &Element, tag: &str) -> Result<Self, TokenStream> {
+        let map = attr_map(el, Self::ATTR_NAMES, tag)?;
 
         Ok(Attributes {
             color: color_tokens_opt(&map, "color")?,
@@ -0,0 +1,121 @@
+use crate::tags::{Tag, TagAttributes};
+use crate::utils::attr::*;
+use crate::utils::render::render_children_stmt;
+use efx_attrnames::AttrNames;
+use efx_core::Element;
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+
+const DIRS: [&str; 4] = ["left-to-right", "right-to-left", "top-down", "bottom-up"];
+const ALIGNS: [&str; 3] = ["min", "center", "max"];
+
+/// `<Layout>`: children in a `ui.with_layout(egui::Layout::..)` scope.
+/// `<Center>` and `<RightToLeft>` are presets with a fixed direction.
+pub struct Layout {
+    attributes: Attributes,
+    element: Element,
+}
+
+impl Tag for Layout {
+    fn from_element(el: &Element) -> Result<Self, TokenStream>
+    where
+        Self: Sized,
+    {
+        let attributes = Attributes::new(el)?;
+        Ok(Self {
+            attributes,
+            element: el.clone(),
+        })
+    }
+
+    fn content<UI: ToTokens>(&self, _ui: &UI) -> TokenStream {
+        let a = &self.attributes;
+        let dir = match a.dir {
+            0 => quote!(egui::Direction::LeftToRight),
+            1 => quote!(egui::Direction::RightToLeft),
+            2 => quote!(egui::Direction::TopDown),
+            _ => quote!(egui::Direction::BottomUp),
+        };
+        let cross = align_tokens(a.cross_align);
+        let mut layout = quote!( egui::Layout::from_main_dir_and_cross_align(#dir, #cross) );
+
+        if let Some(main) = a.main_align {
+            let main = align_tokens(main);
+            layout.extend(quote!( .with_main_align(#main) ));
+        }
+        if let Some(b) = a.main_justify {
+            layout.extend(quote!( .with_main_justify(#b) ));
+        }
+        if let Some(b) = a.cross_justify {
+            layout.extend(quote!( .with_cross_justify(#b) ));
+        }
+        if let Some(b) = a.wrap {
+            layout.extend(quote!( .with_main_wrap(#b) ));
+        }
+
+        layout
+    }
+
+    fn render<UI: ToTokens>(&self, ui: &UI) -> TokenStream {
+        let layout = self.content(ui);
+        let children = render_children_stmt(&quote!(ui), &self.element.children);
+
+        quote! {
+            #ui.with_layout(#layout, |ui| { #children });
+        }
+    }
+}
+
+fn align_tokens(align: usize) -> TokenStream {
+    match align {
+        0 => quote!(egui::Align::Min),
+        1 => quote!(egui::Align::Center),
+        _ => quote!(egui::Align::Max),
+    }
+}
+
+#[derive(Clone, Debug, AttrNames)]
+struct Attributes {
+    /// Index into `DIRS`.
+    dir: usize,
+    /// Index into `ALIGNS`.
+    #[attr(name = "main-align")]
+    main_align: Option<usize>,
+    #[attr(name = "cross-align")]
+    cross_align: usize,
+    #[attr(name = "main-justify")]
+    main_justify: Option<bool>,
+    #[attr(name = "cross-justify")]
+    cross_justify: Option<bool>,
+    wrap: Option<bool>,
+}
+
+impl TagAttributes for Attributes {
+    fn new(el: &Element) -> Result<Self, TokenStream> {
+        let tag = el.name.as_str();
+        // Presets fix the direction and change the defaults of the alignment
+        let (dir, main_align, cross_align) = match tag {
+            "Center" => (Some(2), Some(1), 1),
+            "RightToLeft" => (Some(1), None, 1),
+            _ => (None, None, 0),
+        };
+        let known: Vec<&str> = Attributes::ATTR_NAMES
+            .iter()
+            .copied()
+            .filter(|name| dir.is_none() || *name != "dir")
+            .collect();
+        let map = attr_map(el, &known, tag)?;
+
+        Ok(Attributes {
+            dir: match dir {
+                Some(d) => d,
+                None => enum_opt(&map, "dir", &DIRS)?.unwrap_or(2),
+            },
+            main_align: enum_opt(&map, "main-align", &ALIGNS)?.or(main_align),
+            cross_align: enum_opt(&map, "cross-align", &ALIGNS)?.unwrap_or(cross_align),
+            main_justify: bool_opt(&map, "main-justify")?,
+            cross_justify: bool_opt(&map, "cross-justify")?,
+            wrap: bool_opt(&map, "wrap")?,
+        })
+    }
+}
@@ -0,0 +1,151 @@
+use crate::render::render_node_stmt;
+use crate::tags::{Tag, TagAttributes};
+use crate::utils::attr::*;
+use crate::utils::buffer::{build_buffer_from_children, build_buffer_from_el};
+use crate::utils::expr::expr_opt;
+use efx_attrnames::AttrNames;
+use efx_core::{Element, Node};
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+
+/// `<MenuBar>`: a horizontal bar of `<Menu>` buttons (`egui::MenuBar`).
+pub struct MenuBar {
+    element: Element,
+}
+
+impl Tag for MenuBar {
+    fn from_element(el: &Element) -> Result<Self, TokenStream>
+    where
+        Self: Sized,
+    {
+        attr_map(el, &[], "MenuBar")?;
+        Ok(Self {
+            element: el.clone(),
+        })
+    }
+
+    fn content<UI: ToTokens>(&self, _ui: &UI) -> TokenStream {
+        menu_children(&self.element.children)
+    }
+
+    fn render<UI: ToTokens>(&self, ui: &UI) -> TokenStream {
+        let children = self.content(ui);
+        quote! {
+            egui::MenuBar::new().ui(#ui, |ui| { #children });
+        }
+    }
+}
+
+/// `<Menu title="..">`: a menu button, or a submenu when nested into another `<Menu>`.
+pub struct Menu {
+    title: (TokenStream, TokenStream),
+    element: Element,
+}
+
+impl Tag for Menu {
+    fn from_element(el: &Element) -> Result<Self, TokenStream>
+    where
+        Self: Sized,
+    {
+        attr_map(el, &["title"], "Menu")?;
+        let title = match build_buffer_from_el(el, "title")? {
+            Some(buf) => buf,
+            None => {
+                return Err(quote! { compile_error!("efx: <Menu> requires attribute `title`"); });
+            }
+        };
+        Ok(Self {
+            title,
+            element: el.clone(),
+        })
+    }
+
+    fn content<UI: ToTokens>(&self, _ui: &UI) -> TokenStream {
+        menu_children(&self.element.children)
+    }
+
+    fn render<UI: ToTokens>(&self, ui: &UI) -> TokenStream {
+        let (title_init, title_build) = &self.title;
+        let children = self.content(ui);
+        quote! {{
+            #title_init
+            #title_build
+            #ui.menu_button(__efx_buf, |ui| { #children });
+        }}
+    }
+}
+
+/// Menu body: items and separators, any other node is rendered as usual (checkboxes, labels, ...).
+fn menu_children(children: &[Node]) -> TokenStream {
+    let mut out = TokenStream::new();
+    for ch in children {
+        match ch {
+            Node::Text(t) if t.value.trim().is_empty() => {}
+            Node::Element(el) if el.name == "MenuItem" => out.extend(render_item(el)),
+            Node::Element(el) if el.name == "MenuSeparator" => out.extend(render_separator(el)),
+            _ => out.extend(render_node_stmt(&quote!(ui), ch)),
+        }
+    }
+    out
+}
+
+fn render_item(el: &Element) -> TokenStream {
+    let attributes = match ItemAttributes::new(el) {
+        Ok(a) => a,
+        Err(err) => return err,
+    };
+    let (buf_init, buf_build) = build_buffer_from_children(&el.children);
+
+    let mut build = quote!( let mut __efx_item = egui::Button::new(__efx_buf); );
+    if let Some(shortcut) = &attributes.shortcut {
+        build.extend(quote!( __efx_item = __efx_item.shortcut_text(#shortcut); ));
+    }
+    let enabled = match &attributes.enabled {
+        Some(e) => quote!(#e),
+        None => quote!(true),
+    };
+    let on_click = match &attributes.on_click {
+        Some(handler) => quote!( (#handler)(); ),
+        None => quote!(),
+    };
+
+    quote! {{
+        #buf_init
+        #buf_build
+        #build
+        if ui.add_enabled(#enabled, __efx_item).clicked() {
+            #on_click
+            ui.close();
+        }
+    }}
+}
+
+fn render_separator(el: &Element) -> TokenStream {
+    if !el.children.is_empty() {
+        return quote! { compile_error!("efx: <MenuSeparator/> must be self-closing without children"); };
+    }
+    if let Err(err) = attr_map(el, &[], "MenuSeparator") {
+        return err;
+    }
+    quote! { ui.separator(); }
+}
+
+#[derive(Clone, AttrNames)]
+struct ItemAttributes {
+    #[attr(name = "onClick")]
+    on_click: Option<syn::Expr>,
+    shortcut: Option<String>,
+    enabled: Option<syn::Expr>,
+}
+
+impl TagAttributes for ItemAttributes {
+    fn new(el: &Element) -> Result<Self, TokenStream> {
+        let map = attr_map(el, ItemAttributes::ATTR_NAMES, "MenuItem")?;
+
+        Ok(ItemAttributes {
+            on_click: expr_opt(&map, "onClick")?,
+            shortcut: text_opt(el, "shortcut")?,
+            enabled: expr_opt(&map, "enabled")?,
+        })
+    }
+}
@@ -1,28 +1,79 @@
 pub mod button;
 pub mod central_panel;
+pub mod collapsing;
+pub mod color_picker;
 pub mod column;
+pub mod columns;
+pub mod combo_box;
+pub mod drag_value;
+pub mod frame;
+pub mod grid;
 pub mod hyperlink;
+pub mod image;
 pub mod label;
+pub mod layout;
+pub mod menu;
+pub mod modal;
+pub mod progress_bar;
+pub mod radio_group;
 pub mod row;
 pub mod scroll_area;
 pub mod separator;
+pub mod side_panel;
+pub mod slider;
+pub mod space;
+pub mod spinner;
+#[cfg(feature = "extras")]
+pub mod table;
+pub mod tabs;
+pub mod text;
 pub mod text_field;
+pub mod top_bottom_panel;
 pub mod window;
 
 pub use button::Button;
 pub use central_panel::CentralPanel;
+pub use collapsing::Collapsing;
+pub use color_picker::ColorPicker;
 pub use column::Column;
+pub use columns::Columns;
+pub use combo_box::ComboBox;
+pub use drag_value::DragValue;
 use efx_core::Element;
+pub use frame::Frame;
+pub use grid::Grid;
 pub use hyperlink::Hyperlink;
+pub use image::Image;
 pub use label::Label;
+pub use layout::Layout;
+pub use menu::{Menu, MenuBar};
+pub use modal::Modal;
 use proc_macro2::TokenStream;
+pub use progress_bar::ProgressBar;
 use quote::{quote, ToTokens};
+pub use radio_group::RadioGroup;
 pub use row::Row;
 pub use scroll_area::ScrollArea;
 pub use separator::Separator;
+pub use side_panel::SidePanel;
+pub use slider::Slider;
+pub use space::{Space, Spacer};
+pub use spinner::Spinner;
+#[cfg(feature = "extras")]
+pub use table::Table;
+pub use tabs::Tabs;
+pub use text::{Code, Heading, Small, Strong, Weak};
 pub use text_field::TextField;
+pub use top_bottom_panel::TopBottomPanel;
+pub use window::Window;
 
 pub trait Tag: Sized {
+    /// Own attributes named like shared ones (`utils::shared`), e.g. `width` of `<TextField>`.
+    /// `render_tag` leaves them to the tag.
+    const ATTR_NAMES: &'static [&'static str] = &[];
+    /// `false` for tags shown on the `Context` (panels, windows), which take no shared attributes.
+    const SHARED_ATTRS: bool = true;
+
     /// Constructor from Element (parses attributes and captures children inside self).
     fn from_element(el: &Element) -> Result<Self, TokenStream>
     where
@@ -34,6 +85,9 @@ pub trait Tag: Sized {
 }
 
 pub(crate) trait Block: Sized {
+    const ATTR_NAMES: &'static [&'static str] = &[];
+    const SHARED_ATTRS: bool = true;
+
     fn from_element(el: &Element) -> Result<Self, TokenStream>
     where
         Self: Sized;
@@ -49,6 +103,9 @@ pub(crate) trait Block: Sized {
 }
 
 impl<T: Block> Tag for T {
+    const ATTR_NAMES: &'static [&'static str] = <T as Block>::ATTR_NAMES;
+    const SHARED_ATTRS: bool = <T as Block>::SHARED_ATTRS;
+
     fn from_element(el: &Element) -> Result<Self, TokenStream> {
         <T as Block>::from_element(el)
     }
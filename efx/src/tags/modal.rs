@@ -0,0 +1,147 @@
+use crate::render::{render_node_stmt, render_tag};
+use crate::tags::{Button, Tag, TagAttributes};
+use crate::utils::attr::*;
+use crate::utils::expr::{expr_opt, expr_req, mut_ref_tokens};
+use crate::utils::render::auto_id_salt;
+use efx_attrnames::AttrNames;
+use efx_core::{Element, Node};
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+
+/// `<Modal>` backed by `egui::Modal`: backdrop, focus trap, Escape / click outside closes it.
+/// A click on a `<Button>` inside `<ModalActions>` closes it as well.
+pub struct Modal {
+    attributes: Attributes,
+    element: Element,
+}
+
+impl Tag for Modal {
+    const SHARED_ATTRS: bool = false;
+
+    fn from_element(el: &Element) -> Result<Self, TokenStream>
+    where
+        Self: Sized,
+    {
+        let attributes = Attributes::new(el)?;
+        Ok(Self {
+            attributes,
+            element: el.clone(),
+        })
+    }
+
+    fn content<UI: ToTokens>(&self, _ui: &UI) -> TokenStream {
+        let mut out = TokenStream::new();
+        for ch in &self.element.children {
+            match ch {
+                Node::Element(el) if el.name == "ModalActions" => out.extend(render_actions(el)),
+                _ => out.extend(render_node_stmt(&quote!(ui), ch)),
+            }
+        }
+        out
+    }
+
+    fn render<UI: ToTokens>(&self, ui: &UI) -> TokenStream {
+        self.render_with_ctx(&quote!(&#ui.ctx()))
+    }
+}
+
+impl Modal {
+    /// Show the modal on `ctx` (an expression of type `&egui::Context`) while the bound flag is `true`.
+    pub(crate) fn render_with_ctx(&self, ctx: &TokenStream) -> TokenStream {
+        let a = &self.attributes;
+        let open = mut_ref_tokens(&a.open);
+        let id = match &a.id {
+            Some(id) => quote!(#id),
+            None => auto_id_salt(&self.element),
+        };
+        let children = self.content(ctx);
+
+        let mut build = quote!( let mut __efx_modal = egui::Modal::new(egui::Id::new(#id)); );
+        if let Some(color) = &a.backdrop {
+            build.extend(quote!( __efx_modal = __efx_modal.backdrop_color(#color); ));
+        }
+
+        let on_close = match &a.on_close {
+            Some(handler) => quote!( (#handler)(); ),
+            None => quote!(),
+        };
+
+        // `__efx_close` exists only if there are action buttons to set it
+        let has_actions = self.element.children.iter().any(|ch| {
+            matches!(ch, Node::Element(el) if el.name == "ModalActions" && el.children.iter().any(
+                |b| matches!(b, Node::Element(b) if b.name == "Button")
+            ))
+        });
+        let (close_init, close_check) = if has_actions {
+            (
+                quote!( let mut __efx_close = false; ),
+                quote!(__efx_resp.should_close() || __efx_close),
+            )
+        } else {
+            (quote!(), quote!(__efx_resp.should_close()))
+        };
+
+        // The flag is borrowed only for the check and the reset, so children may use the same state
+        quote! {{
+            if *(#open) {
+                #close_init
+                #build
+                let __efx_resp = __efx_modal.show(#ctx, |ui| { #children });
+                if #close_check {
+                    *(#open) = false;
+                    #on_close
+                }
+            }
+        }}
+    }
+}
+
+/// Button row at the bottom of a modal; every `<Button>` in it closes the modal on click.
+fn render_actions(el: &Element) -> TokenStream {
+    if let Err(err) = attr_map(el, &[], "ModalActions") {
+        return err;
+    }
+
+    let mut row = TokenStream::new();
+    for ch in &el.children {
+        match ch {
+            Node::Text(t) if t.value.trim().is_empty() => {}
+            Node::Element(btn) if btn.name == "Button" => {
+                let resp = render_tag::<Button>(&quote!(ui), btn);
+                row.extend(quote! {
+                    if (#resp).clicked() {
+                        __efx_close = true;
+                    }
+                });
+            }
+            _ => row.extend(render_node_stmt(&quote!(ui), ch)),
+        }
+    }
+
+    quote! {
+        ui.separator();
+        ui.horizontal(|ui| { #row });
+    }
+}
+
+#[derive(Clone, AttrNames)]
+struct Attributes {
+    id: Option<String>,
+    open: syn::Expr,
+    #[attr(name = "onClose")]
+    on_close: Option<syn::Expr>,
+    backdrop: Option<TokenStream>,
+}
+
+impl TagAttributes for Attributes {
+    fn new(el: &Element) -> Result<Self, TokenStream> {
+        let map = attr_map(el, Attributes::ATTR_NAMES, "Modal")?;
+
+        Ok(Attributes {
+            id: text_opt(el, "id")?,
+            open: expr_req(&map, "open", "Modal")?,
+            on_close: expr_opt(&map, "onClose")?,
+            backdrop: color_tokens_opt(&map, "backdrop")?,
+        })
+    }
+}
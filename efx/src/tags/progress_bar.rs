@@ -0,0 +1,123 @@
+use crate::tags::{Tag, TagAttributes};
+use crate::utils::attr::*;
+use crate::utils::buffer::build_buffer_from_el;
+use crate::utils::expr::expr_req;
+use crate::utils::shared::{avail_tokens, size_opt, Size};
+use efx_attrnames::AttrNames;
+use efx_core::Element;
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::Expr;
+
+/// <ProgressBar value={frac} [show-percentage] [animate] [text="Uploading {n}/{total}"] [width=..] [height=..] [fill=..] [rounding=..]/>
+pub struct ProgressBar {
+    attributes: Attributes,
+}
+
+impl Tag for ProgressBar {
+    const ATTR_NAMES: &'static [&'static str] = Attributes::ATTR_NAMES;
+
+    fn from_element(el: &Element) -> Result<Self, TokenStream>
+    where
+        Self: Sized,
+    {
+        if !el.children.is_empty() {
+            return Err(quote! {
+                compile_error!("efx: <ProgressBar/> must be self-closing without children");
+            });
+        }
+
+        let attributes = Attributes::new(el)?;
+        Ok(Self { attributes })
+    }
+
+    fn content<UI: ToTokens>(&self, ui: &UI) -> TokenStream {
+        let value = &self.attributes.value;
+        let mut build = avail_tokens(ui, &[self.attributes.width, self.attributes.height]);
+        build.extend(quote!( let mut __efx_pb = egui::ProgressBar::new((#value) as f32); ));
+
+        if let Some((buf_init, buf_build)) = &self.attributes.text {
+            build.extend(quote! {
+                #buf_init
+                #buf_build
+                __efx_pb = __efx_pb.text(__efx_buf);
+            });
+        }
+        if let Some(true) = self.attributes.show_percentage {
+            build.extend(quote!( __efx_pb = __efx_pb.show_percentage(); ));
+        }
+        if let Some(b) = self.attributes.animate {
+            build.extend(quote!( __efx_pb = __efx_pb.animate(#b); ));
+        }
+        if let Some(w) = self.attributes.width {
+            let w = w.tokens(quote!(x));
+            build.extend(quote!( __efx_pb = __efx_pb.desired_width(#w); ));
+        }
+        if let Some(h) = self.attributes.height {
+            let h = h.tokens(quote!(y));
+            build.extend(quote!( __efx_pb = __efx_pb.desired_height(#h); ));
+        }
+        if let Some(ts) = &self.attributes.fill {
+            build.extend(quote!( __efx_pb = __efx_pb.fill(#ts); ));
+        }
+        if let Some(r) = self.attributes.rounding {
+            build.extend(
+                quote!( __efx_pb = __efx_pb.corner_radius(egui::CornerRadius::same(#r as _)); ),
+            );
+        }
+
+        build
+    }
+
+    fn render<UI: ToTokens>(&self, ui: &UI) -> TokenStream {
+        let build = self.content(ui);
+
+        quote! {{
+            #build
+            let _ = #ui.add(__efx_pb);
+        }}
+    }
+}
+
+#[derive(Clone, AttrNames)]
+struct Attributes {
+    value: Expr,
+    // buffer (init, build) with interpolations
+    text: Option<(TokenStream, TokenStream)>,
+    #[attr(name = "show-percentage")]
+    show_percentage: Option<bool>,
+    animate: Option<bool>,
+    width: Option<Size>,
+    height: Option<Size>,
+    fill: Option<TokenStream>,
+    rounding: Option<u8>,
+}
+
+impl TagAttributes for Attributes {
+    fn new(el: &Element) -> Result<Self, TokenStream> {
+        let map = attr_map(el, Attributes::ATTR_NAMES, "ProgressBar")?;
+
+        let value = expr_req(&map, "value", "ProgressBar")?;
+        // egui clamps the fraction silently, a literal out of range is a typo
+        if let Ok(v) = map["value"].trim().parse::<f64>() {
+            if !(0.0..=1.0).contains(&v) {
+                let msg = format!(
+                    "efx: attribute `value` expects a fraction in 0.0..=1.0, got `{}`",
+                    map["value"]
+                );
+                return Err(quote! { compile_error!(#msg); });
+            }
+        }
+
+        Ok(Attributes {
+            value,
+            text: build_buffer_from_el(el, "text")?,
+            show_percentage: bool_opt(&map, "show-percentage")?,
+            animate: bool_opt(&map, "animate")?,
+            width: size_opt(&map, "width")?,
+            height: size_opt(&map, "height")?,
+            fill: color_tokens_opt(&map, "fill")?,
+            rounding: u8_opt(&map, "rounding")?,
+        })
+    }
+}
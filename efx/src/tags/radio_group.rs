@@ -0,0 +1,120 @@
+use crate::tags::{Tag, TagAttributes};
+use crate::utils::attr::*;
+use crate::utils::buffer::build_buffer_from_children;
+use crate::utils::expr::{expr_req, mut_ref_tokens};
+use crate::utils::render::child_elements;
+use efx_attrnames::AttrNames;
+use efx_core::Element;
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::Expr;
+
+/// <RadioGroup value={&mut state.mode} [direction="horizontal|vertical"]>
+///     <Radio value={Mode::Fast}>Fast</Radio>
+/// </RadioGroup>
+pub struct RadioGroup {
+    attributes: Attributes,
+    options: Vec<Radio>,
+}
+
+impl Tag for RadioGroup {
+    fn from_element(el: &Element) -> Result<Self, TokenStream>
+    where
+        Self: Sized,
+    {
+        let attributes = Attributes::new(el)?;
+        let options = child_elements(&el.children, "RadioGroup", &["Radio"])?
+            .into_iter()
+            .map(Radio::from_element)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            attributes,
+            options,
+        })
+    }
+
+    fn content<UI: ToTokens>(&self, ui: &UI) -> TokenStream {
+        let mut out = TokenStream::new();
+        for radio in &self.options {
+            out.extend(radio.render(ui));
+        }
+        out
+    }
+
+    fn render<UI: ToTokens>(&self, ui: &UI) -> TokenStream {
+        let current = mut_ref_tokens(&self.attributes.value);
+        let options = self.content(&quote!(ui));
+
+        let layout = match self.attributes.direction {
+            Some(0) => quote!(horizontal),
+            _ => quote!(vertical),
+        };
+
+        quote! {{
+            let __efx_radio_current = #current;
+            #ui.#layout(|ui| {
+                #options
+            });
+        }}
+    }
+}
+
+/// Single option of a `RadioGroup`: `<Radio value={expr}>label</Radio>`.
+struct Radio {
+    attributes: RadioAttributes,
+    element: Element,
+}
+
+impl Radio {
+    fn from_element(el: &Element) -> Result<Self, TokenStream> {
+        let attributes = RadioAttributes::new(el)?;
+        Ok(Self {
+            attributes,
+            element: el.clone(),
+        })
+    }
+
+    fn render<UI: ToTokens>(&self, ui: &UI) -> TokenStream {
+        let value = &self.attributes.value;
+        let (buf_init, buf_build) = build_buffer_from_children(&self.element.children);
+
+        quote! {{
+            #buf_init
+            #buf_build
+            #ui.radio_value(&mut *__efx_radio_current, #value, __efx_buf);
+        }}
+    }
+}
+
+#[derive(Clone, AttrNames)]
+struct Attributes {
+    value: Expr,
+    direction: Option<usize>,
+}
+
+#[derive(Clone, AttrNames)]
+struct RadioAttributes {
+    value: Expr,
+}
+
+impl TagAttributes for Attributes {
+    fn new(el: &Element) -> Result<Self, TokenStream> {
+        let map = attr_map(el, Attributes::ATTR_NAMES, "RadioGroup")?;
+
+        Ok(Attributes {
+            value: expr_req(&map, "value", "RadioGroup")?,
+            direction: enum_opt(&map, "direction", &["horizontal", "vertical"])?,
+        })
+    }
+}
+
+impl TagAttributes for RadioAttributes {
+    fn new(el: &Element) -> Result<Self, TokenStream> {
+        let map = attr_map(el, RadioAttributes::ATTR_NAMES, "Radio")?;
+
+        Ok(RadioAttributes {
+            value: expr_req(&map, "value", "Radio")?,
+        })
+    }
+}
@@ -1,6 +1,7 @@
-use crate::render::render_nodes_as_stmts;
 use crate::tags::{Block, TagAttributes};
 use crate::utils::attr::*;
+use crate::utils::flex::{is_flex, render_flex_children, JUSTIFY};
+use crate::utils::render::render_flow_children;
 use efx_attrnames::AttrNames;
 use efx_core::Element;
 use proc_macro2::TokenStream;
@@ -21,7 +22,15 @@ impl Block for Row {
     }
 
     fn content<UI: ToTokens>(&self, ui: &UI) -> TokenStream {
-        let body = render_nodes_as_stmts(&quote!(ui), &self.element.children);
+        let children = &self.element.children;
+        let body = if is_flex(self.attributes.justify, children) {
+            match render_flex_children(&self.element, true, self.attributes.justify) {
+                Ok(body) => body,
+                Err(err) => return err,
+            }
+        } else {
+            render_flow_children(&quote!(ui), children, true)
+        };
 
         // align / wrap
         if self.attributes.wrap {
@@ -86,22 +95,28 @@ struct Attributes {
     gap: Option<f32>,
     padding: Option<f32>,
     align: Option<String>,
+    /// Index into `JUSTIFY`.
+    justify: Option<usize>,
     wrap: bool,
 }
 
 impl TagAttributes for Attributes {
     fn new(el: &Element) -> Result<Self, TokenStream> {
-        let map = match attr_map(el, Attributes::ATTR_NAMES, "Row") {
-            Ok(m) => m,
-            Err(err) => return Err(err),
-        };
+        let map = attr_map(el, Attributes::ATTR_NAMES, "Row")?;
 
         let wrap = bool_or(&map, "wrap", false)?;
+        let justify = enum_opt(&map, "justify", &JUSTIFY)?;
+        if wrap && is_flex(justify, &el.children) {
+            return Err(quote! {
+                compile_error!("efx: <Row wrap> cannot distribute space, remove `justify` and `grow`");
+            });
+        }
 
         Ok(Attributes {
             gap: f32_opt(&map, "gap").unwrap_or(None),
             padding: f32_opt(&map, "padding").unwrap_or(None),
-            align: map.get("align").map(|s| (*s).to_string()),
+            align: text_opt(el, "align")?,
+            justify,
             wrap,
         })
     }
@@ -12,6 +12,8 @@ pub struct ScrollArea {
 }
 
 impl Tag for ScrollArea {
+    const ATTR_NAMES: &'static [&'static str] = Attributes::ATTR_NAMES;
+
     fn from_element(el: &Element) -> Result<Self, TokenStream>
     where
         Self: Sized,
@@ -89,10 +91,7 @@ struct Attributes {
 
 impl TagAttributes for Attributes {
     fn new(el: &Element) -> Result<Self, TokenStream> {
-        let map = match attr_map(el, Attributes::ATTR_NAMES, "Button") {
-            Ok(m) => m,
-            Err(err) => return Err(err),
-        };
+        let map = attr_map(el, Attributes::ATTR_NAMES, "ScrollArea")?;
 
         let axis_src = map.get("axis").copied().unwrap_or("vertical");
         let axis_ctor = match axis_src {
@@ -109,7 +108,7 @@ impl TagAttributes for Attributes {
         };
 
         Ok(Attributes {
-            id: map.get("id").map(|s| (*s).to_string()),
+            id: text_opt(el, "id")?,
             always_show: bool_opt(&map, "always-show")?,
             max_width: f32_opt(&map, "max-width")?,
             max_height: f32_opt(&map, "max-height")?,
@@ -0,0 +1,125 @@
+use crate::tags::{Tag, TagAttributes};
+use crate::utils::attr::*;
+use crate::utils::frame::FrameAttributes;
+use crate::utils::render::render_children_stmt;
+use efx_attrnames::AttrNames;
+use efx_core::Element;
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+
+pub struct SidePanel {
+    attributes: Attributes,
+    element: Element,
+}
+
+impl Tag for SidePanel {
+    const SHARED_ATTRS: bool = false;
+
+    fn from_element(el: &Element) -> Result<Self, TokenStream>
+    where
+        Self: Sized,
+    {
+        let attributes = Attributes::new(el)?;
+        Ok(Self {
+            attributes,
+            element: el.clone(),
+        })
+    }
+
+    fn content<UI: ToTokens>(&self, ctx: &UI) -> TokenStream {
+        let a = &self.attributes;
+        let id = &a.id;
+        let mut build = match a.side {
+            Some(1) => quote!( let mut __efx_panel = egui::SidePanel::right(#id); ),
+            _ => quote!( let mut __efx_panel = egui::SidePanel::left(#id); ),
+        };
+
+        if let Some(b) = a.resizable {
+            build.extend(quote!( __efx_panel = __efx_panel.resizable(#b); ));
+        }
+        if let Some(b) = a.show_separator {
+            build.extend(quote!( __efx_panel = __efx_panel.show_separator_line(#b); ));
+        }
+        if let Some(w) = a.default_width {
+            build.extend(quote!( __efx_panel = __efx_panel.default_width(#w as f32); ));
+        }
+        if let Some(w) = a.min_width {
+            build.extend(quote!( __efx_panel = __efx_panel.min_width(#w as f32); ));
+        }
+        if let Some(w) = a.max_width {
+            build.extend(quote!( __efx_panel = __efx_panel.max_width(#w as f32); ));
+        }
+
+        // Keep the panel frame of the current style unless frame attributes are given
+        if a.frame.is_set() {
+            let frame_build = a
+                .frame
+                .build(quote!(egui::Frame::side_top_panel(&(#ctx).style())));
+            build.extend(quote! {
+                #frame_build
+                __efx_panel = __efx_panel.frame(__efx_frame);
+            });
+        }
+
+        build
+    }
+
+    fn render<UI: ToTokens>(&self, ui: &UI) -> TokenStream {
+        self.render_with_ctx(&quote!(&#ui.ctx()))
+    }
+}
+
+impl SidePanel {
+    /// Show the panel on `ctx` (an expression of type `&egui::Context`).
+    pub(crate) fn render_with_ctx(&self, ctx: &TokenStream) -> TokenStream {
+        let children = render_children_stmt(&quote!(ui), &self.element.children);
+        let build = self.content(ctx);
+
+        quote! {{
+            #build
+            __efx_panel.show(#ctx, |ui| { #children });
+        }}
+    }
+}
+
+#[derive(Clone, Debug, AttrNames)]
+struct Attributes {
+    id: String,
+    side: Option<usize>,
+    resizable: Option<bool>,
+    #[attr(name = "show-separator")]
+    show_separator: Option<bool>,
+    #[attr(name = "default-width")]
+    default_width: Option<f32>,
+    #[attr(name = "min-width")]
+    min_width: Option<f32>,
+    #[attr(name = "max-width")]
+    max_width: Option<f32>,
+    #[attr(skip)]
+    frame: FrameAttributes,
+}
+
+impl TagAttributes for Attributes {
+    fn new(el: &Element) -> Result<Self, TokenStream> {
+        let known = [Attributes::ATTR_NAMES, FrameAttributes::ATTR_NAMES].concat();
+        let map = attr_map(el, &known, "SidePanel")?;
+
+        let id = match text_opt(el, "id")? {
+            Some(id) => id,
+            None => {
+                return Err(quote! { compile_error!("efx: <SidePanel> requires attribute `id`"); });
+            }
+        };
+
+        Ok(Attributes {
+            id,
+            side: enum_opt(&map, "side", &["left", "right"])?,
+            resizable: bool_opt(&map, "resizable")?,
+            show_separator: bool_opt(&map, "show-separator")?,
+            default_width: f32_opt(&map, "default-width")?,
+            min_width: f32_opt(&map, "min-width")?,
+            max_width: f32_opt(&map, "max-width")?,
+            frame: FrameAttributes::from_map(&map)?,
+        })
+    }
+}
@@ -0,0 +1,112 @@
+use crate::tags::{Tag, TagAttributes};
+use crate::utils::attr::*;
+use crate::utils::expr::{expr_req, mut_ref_tokens};
+use efx_attrnames::AttrNames;
+use efx_core::Element;
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::Expr;
+
+/// <Slider value={&mut v} min="0" max="100" [step=..] [prefix=..] [suffix=..] [text=..] [logarithmic] [vertical]/>
+pub struct Slider {
+    attributes: Attributes,
+}
+
+impl Tag for Slider {
+    fn from_element(el: &Element) -> Result<Self, TokenStream>
+    where
+        Self: Sized,
+    {
+        if !el.children.is_empty() {
+            return Err(quote! {
+                compile_error!("efx: <Slider/> must be self-closing without children");
+            });
+        }
+
+        let attributes = Attributes::new(el)?;
+        Ok(Self { attributes })
+    }
+
+    fn content<UI: ToTokens>(&self, _ui: &UI) -> TokenStream {
+        let value = mut_ref_tokens(&self.attributes.value);
+        let (min, max) = (self.attributes.min, self.attributes.max);
+
+        let mut build =
+            quote!( let mut __efx_slider = egui::Slider::new(#value, (#min as _)..=(#max as _)); );
+
+        if let Some(step) = self.attributes.step {
+            build.extend(quote!( __efx_slider = __efx_slider.step_by(#step); ));
+        }
+        if let Some(p) = &self.attributes.prefix {
+            build.extend(quote!( __efx_slider = __efx_slider.prefix(#p); ));
+        }
+        if let Some(s) = &self.attributes.suffix {
+            build.extend(quote!( __efx_slider = __efx_slider.suffix(#s); ));
+        }
+        if let Some(t) = &self.attributes.text {
+            build.extend(quote!( __efx_slider = __efx_slider.text(#t); ));
+        }
+        if let Some(d) = self.attributes.decimals {
+            build.extend(quote!( __efx_slider = __efx_slider.fixed_decimals(#d as usize); ));
+        }
+        if let Some(b) = self.attributes.logarithmic {
+            build.extend(quote!( __efx_slider = __efx_slider.logarithmic(#b); ));
+        }
+        if let Some(true) = self.attributes.vertical {
+            build.extend(quote!( __efx_slider = __efx_slider.vertical(); ));
+        }
+        if let Some(b) = self.attributes.show_value {
+            build.extend(quote!( __efx_slider = __efx_slider.show_value(#b); ));
+        }
+
+        build
+    }
+
+    fn render<UI: ToTokens>(&self, ui: &UI) -> TokenStream {
+        let build = self.content(ui);
+
+        quote! {{
+            #build
+            let _ = #ui.add(__efx_slider);
+        }}
+    }
+}
+
+#[derive(Clone, AttrNames)]
+struct Attributes {
+    value: Expr,
+    min: f64,
+    max: f64,
+    step: Option<f64>,
+    prefix: Option<String>,
+    suffix: Option<String>,
+    text: Option<String>,
+    decimals: Option<u8>,
+    logarithmic: Option<bool>,
+    vertical: Option<bool>,
+    #[attr(name = "show-value")]
+    show_value: Option<bool>,
+}
+
+impl TagAttributes for Attributes {
+    fn new(el: &Element) -> Result<Self, TokenStream> {
+        let map = attr_map(el, Attributes::ATTR_NAMES, "Slider")?;
+
+        let value = expr_req(&map, "value", "Slider")?;
+        let (min, max) = bounds_req(&map, "min", "max", "Slider")?;
+
+        Ok(Attributes {
+            value,
+            min,
+            max,
+            step: f64_opt(&map, "step")?,
+            prefix: text_opt(el, "prefix")?,
+            suffix: text_opt(el, "suffix")?,
+            text: text_opt(el, "text")?,
+            decimals: u8_opt(&map, "decimals")?,
+            logarithmic: bool_opt(&map, "logarithmic")?,
+            vertical: bool_opt(&map, "vertical")?,
+            show_value: bool_opt(&map, "show-value")?,
+        })
+    }
+}
@@ -0,0 +1,94 @@
+use crate::tags::{Tag, TagAttributes};
+use crate::utils::attr::*;
+use efx_attrnames::AttrNames;
+use efx_core::Element;
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+
+/// <Space size="N"/>: fixed gap along the current layout direction (`ui.add_space`).
+pub struct Space {
+    attributes: Attributes,
+}
+
+impl Tag for Space {
+    fn from_element(el: &Element) -> Result<Self, TokenStream>
+    where
+        Self: Sized,
+    {
+        if !el.children.is_empty() {
+            return Err(quote! {
+                compile_error!("efx: <Space/> must be self-closing without children");
+            });
+        }
+
+        let attributes = Attributes::new(el)?;
+        Ok(Self { attributes })
+    }
+
+    fn content<UI: ToTokens>(&self, _ui: &UI) -> TokenStream {
+        let size = self.attributes.size;
+        quote!(#size as f32)
+    }
+
+    fn render<UI: ToTokens>(&self, ui: &UI) -> TokenStream {
+        let size = self.content(ui);
+        quote! { #ui.add_space(#size); }
+    }
+}
+
+/// <Spacer/>: takes the remaining space along the current layout direction.
+/// Inside `<Row>`/`<Column>` the children after it are packed to the far end instead, see `render_flow_children`.
+pub struct Spacer;
+
+impl Tag for Spacer {
+    fn from_element(el: &Element) -> Result<Self, TokenStream>
+    where
+        Self: Sized,
+    {
+        if !el.children.is_empty() {
+            return Err(quote! {
+                compile_error!("efx: <Spacer/> must be self-closing without children");
+            });
+        }
+        attr_map(el, &[], "Spacer")?;
+        Ok(Self)
+    }
+
+    fn content<UI: ToTokens>(&self, ui: &UI) -> TokenStream {
+        quote! {
+            let __efx_avail = #ui.available_size_before_wrap();
+            let __efx_size = if #ui.layout().main_dir().is_horizontal() {
+                egui::vec2(__efx_avail.x, 0.0)
+            } else {
+                egui::vec2(0.0, __efx_avail.y)
+            };
+        }
+    }
+
+    fn render<UI: ToTokens>(&self, ui: &UI) -> TokenStream {
+        let size = self.content(ui);
+        // Unbounded directions (e.g. inside a ScrollArea) have nothing to fill
+        quote! {{
+            #size
+            if __efx_size.is_finite() {
+                #ui.allocate_space(__efx_size);
+            }
+        }}
+    }
+}
+
+#[derive(Clone, Debug, AttrNames)]
+struct Attributes {
+    size: f32,
+}
+
+impl TagAttributes for Attributes {
+    fn new(el: &Element) -> Result<Self, TokenStream> {
+        let map = attr_map(el, Attributes::ATTR_NAMES, "Space")?;
+
+        match f32_opt(&map, "size")? {
+            Some(size) => Ok(Attributes { size }),
+            None => Err(quote! { compile_error!("efx: <Space/> requires attribute `size`"); }),
+        }
+    }
+}
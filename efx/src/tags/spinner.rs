@@ -0,0 +1,66 @@
+use crate::tags::{Tag, TagAttributes};
+use crate::utils::attr::*;
+use efx_attrnames::AttrNames;
+use efx_core::Element;
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+
+/// <Spinner [size=..] [color=..]/>
+pub struct Spinner {
+    attributes: Attributes,
+}
+
+impl Tag for Spinner {
+    fn from_element(el: &Element) -> Result<Self, TokenStream>
+    where
+        Self: Sized,
+    {
+        if !el.children.is_empty() {
+            return Err(quote! {
+                compile_error!("efx: <Spinner/> must be self-closing without children");
+            });
+        }
+
+        let attributes = Attributes::new(el)?;
+        Ok(Self { attributes })
+    }
+
+    fn content<UI: ToTokens>(&self, _ui: &UI) -> TokenStream {
+        let mut build = quote!( let mut __efx_spinner = egui::Spinner::new(); );
+
+        if let Some(n) = self.attributes.size {
+            build.extend(quote!( __efx_spinner = __efx_spinner.size(#n as f32); ));
+        }
+        if let Some(ts) = &self.attributes.color {
+            build.extend(quote!( __efx_spinner = __efx_spinner.color(#ts); ));
+        }
+
+        build
+    }
+
+    fn render<UI: ToTokens>(&self, ui: &UI) -> TokenStream {
+        let build = self.content(ui);
+
+        quote! {{
+            #build
+            let _ = #ui.add(__efx_spinner);
+        }}
+    }
+}
+
+#[derive(Clone, Debug, AttrNames)]
+struct Attributes {
+    size: Option<f32>,
+    color: Option<TokenStream>,
+}
+
+impl TagAttributes for Attributes {
+    fn new(el: &Element) -> Result<Self, TokenStream> {
+        let map = attr_map(el, Attributes::ATTR_NAMES, "Spinner")?;
+
+        Ok(Attributes {
+            size: f32_opt(&map, "size")?,
+            color: color_tokens_opt(&map, "color")?,
+        })
+    }
+}
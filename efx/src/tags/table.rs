@@ -0,0 +1,358 @@
+use crate::render::render_node_stmt;
+use crate::tags::{Tag, TagAttributes};
+use crate::utils::attr::*;
+use crate::utils::buffer::build_buffer_from_el;
+use crate::utils::expr::{expr_opt, expr_req, mut_ref_tokens};
+use crate::utils::render::child_elements;
+use efx_attrnames::AttrNames;
+use efx_core::{Element, Node};
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+
+/// `<Table>` backed by `egui_extras::TableBuilder` (feature `extras`).
+/// Children: `<Column/>` declarations followed by a single `<Rows>` with one cell per column.
+pub struct Table {
+    attributes: Attributes,
+    columns: Vec<ColumnAttributes>,
+    rows: RowsAttributes,
+    cells: Vec<Node>,
+}
+
+impl Tag for Table {
+    const ATTR_NAMES: &'static [&'static str] = Attributes::ATTR_NAMES;
+
+    fn from_element(el: &Element) -> Result<Self, TokenStream>
+    where
+        Self: Sized,
+    {
+        let attributes = Attributes::new(el)?;
+
+        let mut columns = Vec::new();
+        let mut rows = None;
+        for child in child_elements(&el.children, "Table", &["Column", "Rows"])? {
+            match child.name.as_str() {
+                "Column" if rows.is_some() => {
+                    return Err(quote! {
+                        compile_error!("efx: <Table> columns must be declared before <Rows>");
+                    });
+                }
+                "Column" => columns.push(ColumnAttributes::new(child)?),
+                _ if rows.is_some() => {
+                    return Err(quote! { compile_error!("efx: <Table> accepts only one <Rows>"); });
+                }
+                _ => rows = Some(child),
+            }
+        }
+
+        let Some(rows_el) = rows else {
+            return Err(quote! { compile_error!("efx: <Table> requires a <Rows> child"); });
+        };
+        if columns.is_empty() {
+            return Err(quote! { compile_error!("efx: <Table> requires at least one <Column/>"); });
+        }
+
+        let cells: Vec<Node> = rows_el
+            .children
+            .iter()
+            .filter(|ch| !matches!(ch, Node::Text(t) if t.value.trim().is_empty()))
+            .cloned()
+            .collect();
+        if cells.len() != columns.len() {
+            let msg = format!(
+                "efx: <Rows> has {} cells, but <Table> declares {} columns",
+                cells.len(),
+                columns.len()
+            );
+            return Err(quote! { compile_error!(#msg); });
+        }
+
+        let sortable = columns.iter().any(|c| c.sort_by.is_some());
+        match (&attributes.sort, sortable) {
+            (Some(_), false) => {
+                return Err(quote! {
+                    compile_error!("efx: <Table> attribute `sort` needs at least one <Column sort-by={..}/>");
+                });
+            }
+            (None, true) => {
+                return Err(quote! {
+                    compile_error!("efx: <Column> attribute `sort-by` requires `sort={&mut ..}` on <Table>");
+                });
+            }
+            _ => {}
+        }
+
+        Ok(Self {
+            attributes,
+            columns,
+            rows: RowsAttributes::new(rows_el)?,
+            cells,
+        })
+    }
+
+    fn content<UI: ToTokens>(&self, ui: &UI) -> TokenStream {
+        let a = &self.attributes;
+        let mut build = quote!( let mut __efx_table = egui_extras::TableBuilder::new(#ui); );
+
+        if let Some(id) = &a.id {
+            build.extend(quote!( __efx_table = __efx_table.id_salt(#id); ));
+        }
+        if let Some(b) = a.striped {
+            build.extend(quote!( __efx_table = __efx_table.striped(#b); ));
+        }
+        if let Some(b) = a.resizable {
+            build.extend(quote!( __efx_table = __efx_table.resizable(#b); ));
+        }
+        if let Some(h) = a.max_height {
+            build.extend(quote!( __efx_table = __efx_table.max_scroll_height(#h as f32); ));
+        }
+
+        for col in &self.columns {
+            let mut column = match col.width {
+                ColumnWidth::Auto => quote!(egui_extras::Column::auto()),
+                ColumnWidth::Remainder => quote!(egui_extras::Column::remainder()),
+                ColumnWidth::Initial(w) => quote!(egui_extras::Column::initial(#w as f32)),
+            };
+            if let Some(w) = col.min_width {
+                column.extend(quote!( .at_least(#w as f32) ));
+            }
+            if let Some(w) = col.max_width {
+                column.extend(quote!( .at_most(#w as f32) ));
+            }
+            build.extend(quote!( __efx_table = __efx_table.column(#column); ));
+        }
+
+        build
+    }
+
+    fn render<UI: ToTokens>(&self, ui: &UI) -> TokenStream {
+        let each = &self.rows.each;
+        let item = &self.rows.item;
+        let height = self.rows.height;
+        let build = self.content(ui);
+
+        // Row order: identity, or a permutation sorted by the active column's `sort-by` key
+        let (sort_init, row_index, sort_apply) = match &self.attributes.sort {
+            Some(sort) => {
+                let sort = mut_ref_tokens(sort);
+                let mut arms = TokenStream::new();
+                for (i, col) in self.columns.iter().enumerate() {
+                    if let Some(key) = &col.sort_by {
+                        // Keys are computed once per row, not twice per comparison
+                        arms.extend(quote! {
+                            #i => {
+                                let __efx_keys: Vec<_> = __efx_rows
+                                    .iter()
+                                    .map(|__efx_item| {
+                                        #[allow(unused_variables)]
+                                        let #item = __efx_item;
+                                        #key
+                                    })
+                                    .collect();
+                                __efx_order.sort_by(|__efx_a, __efx_b| {
+                                    __efx_keys[*__efx_a]
+                                        .partial_cmp(&__efx_keys[*__efx_b])
+                                        .unwrap_or(::std::cmp::Ordering::Equal)
+                                });
+                            }
+                        });
+                    }
+                }
+                let init = quote! {
+                    let __efx_sort: &mut Option<(usize, bool)> = #sort;
+                    let mut __efx_order: Vec<usize> = (0..__efx_rows.len()).collect();
+                    if let Some((__efx_col, __efx_asc)) = *__efx_sort {
+                        match __efx_col {
+                            #arms
+                            _ => {}
+                        }
+                        if !__efx_asc {
+                            __efx_order.reverse();
+                        }
+                    }
+                    let mut __efx_sort_click: Option<usize> = None;
+                };
+                let apply = quote! {
+                    if let Some(__efx_col) = __efx_sort_click {
+                        *__efx_sort = match *__efx_sort {
+                            Some((__efx_prev, __efx_asc)) if __efx_prev == __efx_col => Some((__efx_col, !__efx_asc)),
+                            _ => Some((__efx_col, true)),
+                        };
+                    }
+                };
+                (init, quote!(__efx_order[__efx_row.index()]), apply)
+            }
+            None => (quote!(), quote!(__efx_row.index()), quote!()),
+        };
+
+        let mut cells = TokenStream::new();
+        for cell in &self.cells {
+            let stmt = render_node_stmt(&quote!(ui), cell);
+            cells.extend(quote! { __efx_row.col(|ui| { #stmt }); });
+        }
+        let body = quote! {
+            .body(|__efx_body| {
+                __efx_body.rows(#height as f32, __efx_rows.len(), |mut __efx_row| {
+                    #[allow(unused_variables)]
+                    let #item = &__efx_rows[#row_index];
+                    #cells
+                });
+            })
+        };
+
+        let table = if self.columns.iter().any(|c| c.header.is_some()) {
+            let header_height = self.attributes.header_height.unwrap_or(20.0);
+            let mut header_cells = TokenStream::new();
+            for (i, col) in self.columns.iter().enumerate() {
+                let cell = match (&col.header, &col.sort_by) {
+                    (Some((init, build)), Some(_)) => quote! {
+                        #init
+                        #build
+                        match *__efx_sort {
+                            Some((__efx_col, true)) if __efx_col == #i => __efx_buf.push_str(" ⏶"),
+                            Some((__efx_col, false)) if __efx_col == #i => __efx_buf.push_str(" ⏷"),
+                            _ => {}
+                        }
+                        let __efx_text = egui::RichText::new(__efx_buf).strong();
+                        if ui.add(egui::Button::new(__efx_text).frame(false)).clicked() {
+                            __efx_sort_click = Some(#i);
+                        }
+                    },
+                    (Some((init, build)), None) => quote! {
+                        #init
+                        #build
+                        ui.strong(__efx_buf);
+                    },
+                    (None, _) => quote!(),
+                };
+                header_cells.extend(quote! { __efx_header.col(|ui| { #cell }); });
+            }
+            quote! {
+                __efx_table
+                    .header(#header_height as f32, |mut __efx_header| { #header_cells })
+                    #body;
+            }
+        } else {
+            quote! { __efx_table #body; }
+        };
+
+        quote! {{
+            let __efx_rows = &(#each)[..];
+            #sort_init
+            #build
+            #table
+            #sort_apply
+        }}
+    }
+}
+
+#[derive(Clone, AttrNames)]
+struct Attributes {
+    id: Option<String>,
+    striped: Option<bool>,
+    resizable: Option<bool>,
+    #[attr(name = "header-height")]
+    header_height: Option<f32>,
+    #[attr(name = "max-height")]
+    max_height: Option<f32>,
+    sort: Option<syn::Expr>,
+}
+
+impl TagAttributes for Attributes {
+    fn new(el: &Element) -> Result<Self, TokenStream> {
+        let map = attr_map(el, Attributes::ATTR_NAMES, "Table")?;
+
+        Ok(Attributes {
+            id: text_opt(el, "id")?,
+            striped: bool_opt(&map, "striped")?,
+            resizable: bool_opt(&map, "resizable")?,
+            header_height: f32_opt(&map, "header-height")?,
+            max_height: f32_opt(&map, "max-height")?,
+            sort: expr_opt(&map, "sort")?,
+        })
+    }
+}
+
+#[derive(Clone, Copy)]
+enum ColumnWidth {
+    Auto,
+    Remainder,
+    Initial(f32),
+}
+
+#[derive(Clone, AttrNames)]
+struct ColumnAttributes {
+    width: ColumnWidth,
+    /// Header text with interpolations, `(init, build)` of `__efx_buf`.
+    header: Option<(TokenStream, TokenStream)>,
+    #[attr(name = "sort-by")]
+    sort_by: Option<syn::Expr>,
+    #[attr(name = "min-width")]
+    min_width: Option<f32>,
+    #[attr(name = "max-width")]
+    max_width: Option<f32>,
+}
+
+impl TagAttributes for ColumnAttributes {
+    fn new(el: &Element) -> Result<Self, TokenStream> {
+        if !el.children.is_empty() {
+            return Err(quote! {
+                compile_error!("efx: <Column> inside <Table> must be self-closing, cells go into <Rows>");
+            });
+        }
+        let map = attr_map(el, ColumnAttributes::ATTR_NAMES, "Column")?;
+
+        let width = match map.get("width").copied() {
+            None | Some("auto") => ColumnWidth::Auto,
+            Some("remainder") => ColumnWidth::Remainder,
+            Some(v) => match v.parse::<f32>() {
+                Ok(w) => ColumnWidth::Initial(w),
+                Err(_) => {
+                    let msg = format!(
+                        "efx: attribute `width` expects auto|remainder|N, got `{}`",
+                        v
+                    );
+                    return Err(quote! { compile_error!(#msg); });
+                }
+            },
+        };
+        Ok(ColumnAttributes {
+            width,
+            header: build_buffer_from_el(el, "header")?,
+            sort_by: expr_opt(&map, "sort-by")?,
+            min_width: f32_opt(&map, "min-width")?,
+            max_width: f32_opt(&map, "max-width")?,
+        })
+    }
+}
+
+#[derive(Clone, AttrNames)]
+struct RowsAttributes {
+    each: syn::Expr,
+    #[attr(name = "as")]
+    item: syn::Ident,
+    height: f32,
+}
+
+impl TagAttributes for RowsAttributes {
+    fn new(el: &Element) -> Result<Self, TokenStream> {
+        let map = attr_map(el, RowsAttributes::ATTR_NAMES, "Rows")?;
+
+        let item_src = map.get("as").copied().unwrap_or("row");
+        let item = match syn::parse_str::<syn::Ident>(item_src) {
+            Ok(ident) => ident,
+            Err(_) => {
+                let msg = format!(
+                    "efx: attribute `as` must be an identifier, got `{}`",
+                    item_src
+                );
+                return Err(quote! { compile_error!(#msg); });
+            }
+        };
+
+        Ok(RowsAttributes {
+            each: expr_req(&map, "each", "Rows")?,
+            item,
+            height: f32_opt(&map, "height")?.unwrap_or(18.0),
+        })
+    }
+}
@@ -0,0 +1,179 @@
+use crate::tags::{Tag, TagAttributes};
+use crate::utils::attr::*;
+use crate::utils::buffer::build_buffer_from_el;
+use crate::utils::expr::{expr_req, mut_ref_tokens};
+use crate::utils::render::{child_elements, render_children_stmt};
+use efx_attrnames::AttrNames;
+use efx_core::Element;
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+
+pub struct Tabs {
+    attributes: Attributes,
+    tabs: Vec<(TabAttributes, Element)>,
+}
+
+impl Tag for Tabs {
+    fn from_element(el: &Element) -> Result<Self, TokenStream>
+    where
+        Self: Sized,
+    {
+        let attributes = Attributes::new(el)?;
+
+        let mut tabs = Vec::new();
+        for tab in child_elements(&el.children, "Tabs", &["Tab"])? {
+            tabs.push((TabAttributes::new(tab)?, tab.clone()));
+        }
+        if tabs.is_empty() {
+            return Err(quote! { compile_error!("efx: <Tabs> requires at least one <Tab>"); });
+        }
+
+        Ok(Self { attributes, tabs })
+    }
+
+    /// Tab strip: one label per `<Tab>`, writes a click or an arrow key press into `__efx_next`.
+    fn content<UI: ToTokens>(&self, ui: &UI) -> TokenStream {
+        let count = self.tabs.len();
+        let mut strip = TokenStream::new();
+
+        for (i, (tab, _)) in self.tabs.iter().enumerate() {
+            let (title_init, title_build) = &tab.title;
+            let label = match self.attributes.style {
+                // pills
+                Some(1) => quote! {
+                    let __efx_tab = ui.selectable_label(__efx_active == Some(#i), __efx_buf);
+                },
+                // underline
+                _ => quote! {
+                    let __efx_on = __efx_active == Some(#i);
+                    let mut __efx_text = egui::RichText::new(__efx_buf);
+                    if __efx_on {
+                        __efx_text = __efx_text.strong();
+                    }
+                    let __efx_tab = ui.add(egui::Button::new(__efx_text).frame(false));
+                    if __efx_on {
+                        let __efx_stroke = ui.visuals().selection.stroke;
+                        ui.painter().hline(
+                            __efx_tab.rect.x_range(),
+                            __efx_tab.rect.bottom() + __efx_stroke.width,
+                            __efx_stroke,
+                        );
+                    }
+                },
+            };
+            strip.extend(quote! {
+                #title_init
+                #title_build
+                #label
+                if __efx_tab.clicked() {
+                    __efx_next = Some(#i);
+                }
+                __efx_focus |= __efx_tab.has_focus();
+            });
+        }
+
+        if count == 1 {
+            return quote! { #ui.horizontal(|ui| { #strip }); };
+        }
+
+        quote! {
+            #ui.horizontal(|ui| { #strip });
+
+            // Left/right arrows switch tabs while a tab label has keyboard focus
+            if __efx_focus {
+                let (__efx_left, __efx_right) = #ui.input(|i| {
+                    (i.key_pressed(egui::Key::ArrowLeft), i.key_pressed(egui::Key::ArrowRight))
+                });
+                let __efx_cur = __efx_active.unwrap_or(0);
+                if __efx_left {
+                    __efx_next = Some((__efx_cur + #count - 1) % #count);
+                } else if __efx_right {
+                    __efx_next = Some((__efx_cur + 1) % #count);
+                }
+            }
+        }
+    }
+
+    fn render<UI: ToTokens>(&self, ui: &UI) -> TokenStream {
+        let selected = mut_ref_tokens(&self.attributes.selected);
+
+        let mut active_checks = TokenStream::new();
+        let mut assigns = TokenStream::new();
+        let mut bodies = TokenStream::new();
+        for (i, (tab, el)) in self.tabs.iter().enumerate() {
+            let value = &tab.value;
+            active_checks.extend(quote! {
+                if *__efx_sel == (#value) { Some(#i) } else
+            });
+            assigns.extend(quote! { #i => *__efx_sel = #value, });
+            let children = render_children_stmt(ui, &el.children);
+            bodies.extend(quote! { Some(#i) => { #children } });
+        }
+
+        let strip = self.content(ui);
+
+        // The binding is evaluated once; the borrow ends before the bodies, which may read the state
+        quote! {{
+            let __efx_sel = #selected;
+            let mut __efx_active: Option<usize> = #active_checks { None };
+            let mut __efx_next: Option<usize> = None;
+            let mut __efx_focus = false;
+
+            #strip
+
+            if let Some(__efx_i) = __efx_next {
+                match __efx_i {
+                    #assigns
+                    _ => {}
+                }
+                __efx_active = Some(__efx_i);
+            }
+
+            // Only the active tab's body is rendered
+            match __efx_active {
+                #bodies
+                _ => {}
+            }
+        }}
+    }
+}
+
+#[derive(Clone, AttrNames)]
+struct Attributes {
+    selected: syn::Expr,
+    style: Option<usize>,
+}
+
+impl TagAttributes for Attributes {
+    fn new(el: &Element) -> Result<Self, TokenStream> {
+        let map = attr_map(el, Attributes::ATTR_NAMES, "Tabs")?;
+
+        Ok(Attributes {
+            selected: expr_req(&map, "selected", "Tabs")?,
+            style: enum_opt(&map, "style", &["underline", "pills"])?,
+        })
+    }
+}
+
+#[derive(Clone, AttrNames)]
+struct TabAttributes {
+    value: syn::Expr,
+    /// Title text with interpolations, `(init, build)` of `__efx_buf`.
+    title: (TokenStream, TokenStream),
+}
+
+impl TagAttributes for TabAttributes {
+    fn new(el: &Element) -> Result<Self, TokenStream> {
+        let map = attr_map(el, TabAttributes::ATTR_NAMES, "Tab")?;
+
+        let value = expr_req(&map, "value", "Tab")?;
+        let title = match build_buffer_from_el(el, "title")? {
+            Some(buf) => buf,
+            None => {
+                return Err(quote! { compile_error!("efx: <Tab> requires attribute `title`"); });
+            }
+        };
+
+        Ok(TabAttributes { value, title })
+    }
+}
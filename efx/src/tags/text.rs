@@ -0,0 +1,114 @@
+use crate::tags::{Label, Tag};
+use efx_core::Element;
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+
+/// Font size of `<Heading level="N">` relative to `TextStyle::Heading`, for levels 1..=4.
+/// A style entry `TextStyle::Name("hN")` takes precedence over the scale.
+const HEADING_SCALE: [f32; 4] = [1.0, 0.85, 0.72, 0.64];
+
+pub struct Heading {
+    label: Label,
+    level: Option<usize>,
+}
+
+impl Tag for Heading {
+    fn from_element(el: &Element) -> Result<Self, TokenStream>
+    where
+        Self: Sized,
+    {
+        let mut levels = el.attrs.iter().filter(|a| a.name == "level");
+        let level = match (levels.next(), levels.next()) {
+            (None, _) => None,
+            (Some(a), None) => match a.value.parse::<usize>() {
+                Ok(n @ 1..=4) => Some(n),
+                _ => {
+                    let msg = format!("efx: attribute `level` expects 1..4, got `{}`", a.value);
+                    return Err(quote! { compile_error!(#msg); });
+                }
+            },
+            (Some(_), Some(_)) => {
+                return Err(
+                    quote! { compile_error!("efx: <Heading> duplicate attribute `level`"); },
+                );
+            }
+        };
+
+        // Levels 2..=4 get their size from `__efx_heading_size`, bound in `render`.
+        let style = match level {
+            None | Some(1) => quote!(.heading()),
+            Some(_) => quote!(.heading().size(__efx_heading_size)),
+        };
+
+        let mut label_el = el.clone();
+        label_el.attrs.retain(|a| a.name != "level");
+
+        Ok(Self {
+            label: Label::styled(&label_el, "Heading", style)?,
+            level,
+        })
+    }
+
+    fn content<UI: ToTokens>(&self, ui: &UI) -> TokenStream {
+        self.label.content(ui)
+    }
+
+    fn render<UI: ToTokens>(&self, ui: &UI) -> TokenStream {
+        let label = self.label.render(ui);
+
+        match self.level {
+            Some(n) if n > 1 => {
+                let name = format!("h{}", n);
+                let scale = HEADING_SCALE[n - 1];
+                quote! {{
+                    let __efx_heading_size = {
+                        let __efx_style = #ui.style();
+                        match __efx_style.text_styles.get(&egui::TextStyle::Name(#name.into())) {
+                            Some(font) => font.size,
+                            None => __efx_style
+                                .text_styles
+                                .get(&egui::TextStyle::Heading)
+                                .map_or(18.0, |font| font.size) * #scale,
+                        }
+                    };
+                    #label
+                }}
+            }
+            _ => label,
+        }
+    }
+}
+
+/// Text tags that are a `<Label>` with a single `RichText` modifier.
+macro_rules! semantic_text {
+    ($($name:ident => $modifier:ident),* $(,)?) => {$(
+        pub struct $name {
+            label: Label,
+        }
+
+        impl Tag for $name {
+            fn from_element(el: &Element) -> Result<Self, TokenStream>
+            where
+                Self: Sized,
+            {
+                let label = Label::styled(el, stringify!($name), quote!(.$modifier()))?;
+                Ok(Self { label })
+            }
+
+            fn content<UI: ToTokens>(&self, ui: &UI) -> TokenStream {
+                self.label.content(ui)
+            }
+
+            fn render<UI: ToTokens>(&self, ui: &UI) -> TokenStream {
+                self.label.render(ui)
+            }
+        }
+    )*};
+}
+
+semantic_text! {
+    Small => small,
+    Code => code,
+    Strong => strong,
+    Weak => weak,
+}
@@ -1,6 +1,7 @@
 use crate::tags::{Tag, TagAttributes};
 use crate::utils::attr::*;
-use crate::utils::expr::expr_req;
+use crate::utils::expr::{expr_req, mut_ref_tokens};
+use crate::utils::shared::{avail_tokens, size_opt, Size};
 use efx_attrnames::AttrNames;
 use efx_core::Element;
 use proc_macro2::TokenStream;
@@ -12,6 +13,8 @@ pub struct TextField {
 }
 
 impl Tag for TextField {
+    const ATTR_NAMES: &'static [&'static str] = Attributes::ATTR_NAMES;
+
     fn from_element(el: &Element) -> Result<Self, TokenStream>
     where
         Self: Sized,
@@ -27,27 +30,29 @@ impl Tag for TextField {
         Ok(Self { attributes })
     }
 
-    fn content<UI: ToTokens>(&self, _ui: &UI) -> TokenStream {
-        let value = self.attributes.value.clone();
+    fn content<UI: ToTokens>(&self, ui: &UI) -> TokenStream {
+        let value = mut_ref_tokens(&self.attributes.value);
 
         let base = if matches!(self.attributes.multiline, Some(true)) {
-            quote!( egui::TextEdit::multiline(&mut (#value)) )
+            quote!( egui::TextEdit::multiline(#value) )
         } else {
-            quote!( egui::TextEdit::singleline(&mut (#value)) )
+            quote!( egui::TextEdit::singleline(#value) )
         };
 
-        let mut build = quote!( let mut __efx_te = #base; );
+        let mut build = avail_tokens(ui, &[self.attributes.width]);
+        build.extend(quote!( let mut __efx_te = #base; ));
 
         if let Some(h) = self.attributes.hint.clone() {
             build.extend(quote!( __efx_te = __efx_te.hint_text(#h); ));
         }
-        if let Some(pw) = self.attributes.password.clone() {
+        if let Some(pw) = self.attributes.password {
             if pw {
                 build.extend(quote!( __efx_te = __efx_te.password(true); ));
             }
         }
-        if let Some(w) = self.attributes.width.clone() {
-            build.extend(quote!( __efx_te = __efx_te.desired_width(#w as f32); ));
+        if let Some(w) = self.attributes.width {
+            let w = w.tokens(quote!(x));
+            build.extend(quote!( __efx_te = __efx_te.desired_width(#w); ));
         }
 
         build
@@ -67,28 +72,22 @@ impl Tag for TextField {
 struct Attributes {
     value: Expr,
     hint: Option<String>,
-    width: Option<f32>,
+    width: Option<Size>,
     multiline: Option<bool>,
     password: Option<bool>,
 }
 
 impl TagAttributes for Attributes {
     fn new(el: &Element) -> Result<Self, TokenStream> {
-        let map = match attr_map(el, Attributes::ATTR_NAMES, "TextField") {
-            Ok(m) => m,
-            Err(err) => return Err(err),
-        };
+        let map = attr_map(el, Attributes::ATTR_NAMES, "TextField")?;
 
         // value — required (Rust expression without curly braces)
-        let value_expr = match expr_req(&map, "value", "TextField") {
-            Ok(e) => e,
-            Err(err) => return Err(err),
-        };
+        let value_expr = expr_req(&map, "value", "TextField")?;
 
         Ok(Attributes {
             value: value_expr,
-            hint: map.get("hint").map(|s| (*s).to_string()),
-            width: f32_opt(&map, "width")?,
+            hint: text_opt(el, "hint")?,
+            width: size_opt(&map, "width")?,
             multiline: bool_opt(&map, "multiline")?,
             password: bool_opt(&map, "password")?,
         })
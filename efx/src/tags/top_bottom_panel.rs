@@ -0,0 +1,129 @@
+use crate::tags::{Tag, TagAttributes};
+use crate::utils::attr::*;
+use crate::utils::frame::FrameAttributes;
+use crate::utils::render::render_children_stmt;
+use efx_attrnames::AttrNames;
+use efx_core::Element;
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+
+/// `<TopPanel>` and `<BottomPanel>`, both backed by `egui::TopBottomPanel`.
+pub struct TopBottomPanel {
+    bottom: bool,
+    attributes: Attributes,
+    element: Element,
+}
+
+impl Tag for TopBottomPanel {
+    const SHARED_ATTRS: bool = false;
+
+    fn from_element(el: &Element) -> Result<Self, TokenStream>
+    where
+        Self: Sized,
+    {
+        let attributes = Attributes::new(el)?;
+        Ok(Self {
+            bottom: el.name == "BottomPanel",
+            attributes,
+            element: el.clone(),
+        })
+    }
+
+    fn content<UI: ToTokens>(&self, ctx: &UI) -> TokenStream {
+        let a = &self.attributes;
+        let id = &a.id;
+        let mut build = if self.bottom {
+            quote!( let mut __efx_panel = egui::TopBottomPanel::bottom(#id); )
+        } else {
+            quote!( let mut __efx_panel = egui::TopBottomPanel::top(#id); )
+        };
+
+        if let Some(b) = a.resizable {
+            build.extend(quote!( __efx_panel = __efx_panel.resizable(#b); ));
+        }
+        if let Some(b) = a.show_separator {
+            build.extend(quote!( __efx_panel = __efx_panel.show_separator_line(#b); ));
+        }
+        if let Some(h) = a.default_height {
+            build.extend(quote!( __efx_panel = __efx_panel.default_height(#h as f32); ));
+        }
+        if let Some(h) = a.min_height {
+            build.extend(quote!( __efx_panel = __efx_panel.min_height(#h as f32); ));
+        }
+        if let Some(h) = a.max_height {
+            build.extend(quote!( __efx_panel = __efx_panel.max_height(#h as f32); ));
+        }
+
+        // Keep the panel frame of the current style unless frame attributes are given
+        if a.frame.is_set() {
+            let frame_build = a
+                .frame
+                .build(quote!(egui::Frame::side_top_panel(&(#ctx).style())));
+            build.extend(quote! {
+                #frame_build
+                __efx_panel = __efx_panel.frame(__efx_frame);
+            });
+        }
+
+        build
+    }
+
+    fn render<UI: ToTokens>(&self, ui: &UI) -> TokenStream {
+        self.render_with_ctx(&quote!(&#ui.ctx()))
+    }
+}
+
+impl TopBottomPanel {
+    /// Show the panel on `ctx` (an expression of type `&egui::Context`).
+    pub(crate) fn render_with_ctx(&self, ctx: &TokenStream) -> TokenStream {
+        let children = render_children_stmt(&quote!(ui), &self.element.children);
+        let build = self.content(ctx);
+
+        quote! {{
+            #build
+            __efx_panel.show(#ctx, |ui| { #children });
+        }}
+    }
+}
+
+#[derive(Clone, Debug, AttrNames)]
+struct Attributes {
+    id: String,
+    resizable: Option<bool>,
+    #[attr(name = "show-separator")]
+    show_separator: Option<bool>,
+    #[attr(name = "default-height")]
+    default_height: Option<f32>,
+    #[attr(name = "min-height")]
+    min_height: Option<f32>,
+    #[attr(name = "max-height")]
+    max_height: Option<f32>,
+    #[attr(skip)]
+    frame: FrameAttributes,
+}
+
+impl TagAttributes for Attributes {
+    fn new(el: &Element) -> Result<Self, TokenStream> {
+        let tag = el.name.as_str();
+        let known = [Attributes::ATTR_NAMES, FrameAttributes::ATTR_NAMES].concat();
+        let map = attr_map(el, &known, tag)?;
+
+        let id = match text_opt(el, "id")? {
+            Some(id) => id,
+            None => {
+                let msg = format!("efx: <{}> requires attribute `id`", tag);
+                return Err(quote! { compile_error!(#msg); });
+            }
+        };
+
+        Ok(Attributes {
+            id,
+            resizable: bool_opt(&map, "resizable")?,
+            show_separator: bool_opt(&map, "show-separator")?,
+            default_height: f32_opt(&map, "default-height")?,
+            min_height: f32_opt(&map, "min-height")?,
+            max_height: f32_opt(&map, "max-height")?,
+            frame: FrameAttributes::from_map(&map)?,
+        })
+    }
+}
@@ -1 +1,199 @@
+use crate::tags::{Tag, TagAttributes};
+use crate::utils::attr::*;
+use crate::utils::buffer::build_buffer_from_el;
+use crate::utils::expr::{expr_opt, mut_ref_tokens};
+use crate::utils::frame::FrameAttributes;
+use crate::utils::render::render_children_stmt;
+use efx_attrnames::AttrNames;
+use efx_core::Element;
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
 
+const ANCHORS: &[&str] = &[
+    "top-left",
+    "top",
+    "top-right",
+    "left",
+    "center",
+    "right",
+    "bottom-left",
+    "bottom",
+    "bottom-right",
+];
+
+pub struct Window {
+    attributes: Attributes,
+    element: Element,
+}
+
+impl Tag for Window {
+    const SHARED_ATTRS: bool = false;
+
+    fn from_element(el: &Element) -> Result<Self, TokenStream>
+    where
+        Self: Sized,
+    {
+        let attributes = Attributes::new(el)?;
+        Ok(Self {
+            attributes,
+            element: el.clone(),
+        })
+    }
+
+    fn content<UI: ToTokens>(&self, ctx: &UI) -> TokenStream {
+        let a = &self.attributes;
+        let (title_init, title_build) = &a.title;
+        let mut build = quote! {
+            #title_init
+            #title_build
+            let mut __efx_window = egui::Window::new(__efx_buf);
+        };
+
+        if let Some(id) = &a.id {
+            build.extend(quote!( __efx_window = __efx_window.id(egui::Id::new(#id)); ));
+        }
+        if let Some(open) = &a.open {
+            let open = mut_ref_tokens(open);
+            build.extend(quote!( __efx_window = __efx_window.open(#open); ));
+        }
+        if let Some(b) = a.resizable {
+            build.extend(quote!( __efx_window = __efx_window.resizable(#b); ));
+        }
+        if let Some(b) = a.collapsible {
+            build.extend(quote!( __efx_window = __efx_window.collapsible(#b); ));
+        }
+        if let Some(b) = a.movable {
+            build.extend(quote!( __efx_window = __efx_window.movable(#b); ));
+        }
+        if let Some(b) = a.title_bar {
+            build.extend(quote!( __efx_window = __efx_window.title_bar(#b); ));
+        }
+
+        for (value, method) in [
+            (a.default_width, quote!(default_width)),
+            (a.default_height, quote!(default_height)),
+            (a.min_width, quote!(min_width)),
+            (a.min_height, quote!(min_height)),
+            (a.max_width, quote!(max_width)),
+            (a.max_height, quote!(max_height)),
+        ] {
+            if let Some(v) = value {
+                build.extend(quote!( __efx_window = __efx_window.#method(#v as f32); ));
+            }
+        }
+
+        if let Some(anchor) = a.anchor {
+            let align = match ANCHORS[anchor] {
+                "top-left" => quote!(egui::Align2::LEFT_TOP),
+                "top" => quote!(egui::Align2::CENTER_TOP),
+                "top-right" => quote!(egui::Align2::RIGHT_TOP),
+                "left" => quote!(egui::Align2::LEFT_CENTER),
+                "center" => quote!(egui::Align2::CENTER_CENTER),
+                "right" => quote!(egui::Align2::RIGHT_CENTER),
+                "bottom-left" => quote!(egui::Align2::LEFT_BOTTOM),
+                "bottom" => quote!(egui::Align2::CENTER_BOTTOM),
+                _ => quote!(egui::Align2::RIGHT_BOTTOM),
+            };
+            let (x, y) = a.offset.unwrap_or((0.0, 0.0));
+            build.extend(quote!( __efx_window = __efx_window.anchor(#align, egui::vec2(#x as f32, #y as f32)); ));
+        }
+
+        // Only replace the window frame of the current style when asked to
+        if a.frame.is_set() {
+            let frame_build = a.frame.build(quote!(egui::Frame::window(&(#ctx).style())));
+            build.extend(quote! {
+                #frame_build
+                __efx_window = __efx_window.frame(__efx_frame);
+            });
+        }
+
+        build
+    }
+
+    fn render<UI: ToTokens>(&self, ui: &UI) -> TokenStream {
+        self.render_with_ctx(&quote!(&#ui.ctx()))
+    }
+}
+
+impl Window {
+    /// Show the window on `ctx` (an expression of type `&egui::Context`).
+    pub(crate) fn render_with_ctx(&self, ctx: &TokenStream) -> TokenStream {
+        let children = render_children_stmt(&quote!(ui), &self.element.children);
+        let build = self.content(ctx);
+
+        quote! {{
+            #build
+            __efx_window.show(#ctx, |ui| { #children });
+        }}
+    }
+}
+
+#[derive(Clone, AttrNames)]
+struct Attributes {
+    /// Title text with interpolations, `(init, build)` of `__efx_buf`.
+    title: (TokenStream, TokenStream),
+    id: Option<String>,
+    open: Option<syn::Expr>,
+    resizable: Option<bool>,
+    collapsible: Option<bool>,
+    movable: Option<bool>,
+    #[attr(name = "title-bar")]
+    title_bar: Option<bool>,
+    #[attr(name = "default-width")]
+    default_width: Option<f32>,
+    #[attr(name = "default-height")]
+    default_height: Option<f32>,
+    #[attr(name = "min-width")]
+    min_width: Option<f32>,
+    #[attr(name = "min-height")]
+    min_height: Option<f32>,
+    #[attr(name = "max-width")]
+    max_width: Option<f32>,
+    #[attr(name = "max-height")]
+    max_height: Option<f32>,
+    anchor: Option<usize>,
+    offset: Option<(f32, f32)>,
+    #[attr(skip)]
+    frame: FrameAttributes,
+}
+
+impl TagAttributes for Attributes {
+    fn new(el: &Element) -> Result<Self, TokenStream> {
+        let known = [Attributes::ATTR_NAMES, FrameAttributes::ATTR_NAMES].concat();
+        let map = attr_map(el, &known, "Window")?;
+
+        let title = match build_buffer_from_el(el, "title")? {
+            Some(buf) => buf,
+            None => {
+                return Err(quote! { compile_error!("efx: <Window> requires attribute `title`"); });
+            }
+        };
+
+        let anchor = enum_opt(&map, "anchor", ANCHORS)?;
+        let offset = vec2_opt(&map, "offset")?;
+        if offset.is_some() && anchor.is_none() {
+            return Err(quote! {
+                compile_error!("efx: <Window> attribute `offset` requires `anchor`");
+            });
+        }
+
+        Ok(Attributes {
+            title,
+            id: text_opt(el, "id")?,
+            open: expr_opt(&map, "open")?,
+            resizable: bool_opt(&map, "resizable")?,
+            collapsible: bool_opt(&map, "collapsible")?,
+            movable: bool_opt(&map, "movable")?,
+            title_bar: bool_opt(&map, "title-bar")?,
+            default_width: f32_opt(&map, "default-width")?,
+            default_height: f32_opt(&map, "default-height")?,
+            min_width: f32_opt(&map, "min-width")?,
+            min_height: f32_opt(&map, "min-height")?,
+            max_width: f32_opt(&map, "max-width")?,
+            max_height: f32_opt(&map, "max-height")?,
+            anchor,
+            offset,
+            frame: FrameAttributes::from_map(&map)?,
+        })
+    }
+}
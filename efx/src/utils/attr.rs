@@ -16,7 +16,7 @@ pub fn attr_map<'a>(
 
     for a in &el.attrs {
         let name = a.name.as_str();
-        if !known.iter().any(|k| *k == name) {
+        if !known.contains(&name) {
             let msg = format!("efx: <{}> unknown attribute `{}`", tag, name);
             return Err(quote! { compile_error!(#msg); });
         }
@@ -30,6 +30,19 @@ pub fn attr_map<'a>(
     Ok(out)
 }
 
+/// Plain text attribute, used as written; `key={expr}` is rejected instead of taking the
+/// expression source as text.
+pub fn text_opt(el: &Element, key: &str) -> Result<Option<String>, TokenStream> {
+    match el.attrs.iter().find(|a| a.name == key) {
+        None => Ok(None),
+        Some(a) if a.expr => {
+            let msg = format!("efx: attribute `{}` takes text, not an expression", key);
+            Err(quote! { compile_error!(#msg); })
+        }
+        Some(a) => Ok(Some(a.value.clone())),
+    }
+}
+
 #[inline]
 pub fn bool_opt(map: &BTreeMap<&str, &str>, key: &str) -> Result<Option<bool>, TokenStream> {
     Ok(match map.get(key) {
@@ -55,6 +68,67 @@ pub fn f32_opt(map: &BTreeMap<&str, &str>, key: &str) -> Result<Option<f32>, Tok
     })
 }
 
+#[inline]
+pub fn f64_opt(map: &BTreeMap<&str, &str>, key: &str) -> Result<Option<f64>, TokenStream> {
+    Ok(match map.get(key) {
+        Some(v) => Some(A::parse_f64(key, v).map_err(|m| quote! { compile_error!(#m); })?),
+        None => None,
+    })
+}
+
+/// Two-component attribute: `"N"` (same value for both axes) or `"X Y"`, e.g. `spacing="8 4"`.
+pub fn vec2_opt(map: &BTreeMap<&str, &str>, key: &str) -> Result<Option<(f32, f32)>, TokenStream> {
+    let Some(v) = map.get(key) else {
+        return Ok(None);
+    };
+    let parts = v
+        .split_whitespace()
+        .map(|p| A::parse_f32(key, p))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|m| quote! { compile_error!(#m); })?;
+    match parts.as_slice() {
+        [xy] => Ok(Some((*xy, *xy))),
+        [x, y] => Ok(Some((*x, *y))),
+        _ => {
+            let msg = format!(
+                "efx: attribute `{}` expects \"N\" or \"X Y\", got `{}`",
+                key, v
+            );
+            Err(quote! { compile_error!(#msg); })
+        }
+    }
+}
+
+/// `min..=max` range attribute, validated at compile time.
+#[inline]
+pub fn range_opt(map: &BTreeMap<&str, &str>, key: &str) -> Result<Option<(f64, f64)>, TokenStream> {
+    Ok(match map.get(key) {
+        Some(v) => Some(A::parse_range(key, v).map_err(|m| quote! { compile_error!(#m); })?),
+        None => None,
+    })
+}
+
+/// Validates a pair of bound attributes (`min`/`max`): both present and `min <= max`.
+pub fn bounds_req(
+    map: &BTreeMap<&str, &str>,
+    min_key: &str,
+    max_key: &str,
+    tag: &str,
+) -> Result<(f64, f64), TokenStream> {
+    let (min, max) = match (f64_opt(map, min_key)?, f64_opt(map, max_key)?) {
+        (Some(min), Some(max)) => (min, max),
+        _ => {
+            let msg = format!(
+                "efx: <{}> requires `{}` and `{}` attributes",
+                tag, min_key, max_key
+            );
+            return Err(quote! { compile_error!(#msg); });
+        }
+    };
+    A::check_range(max_key, min, max).map_err(|m| quote! { compile_error!(#m); })?;
+    Ok((min, max))
+}
+
 #[inline]
 pub fn u8_opt(map: &BTreeMap<&str, &str>, key: &str) -> Result<Option<u8>, TokenStream> {
     Ok(match map.get(key) {
@@ -63,6 +137,38 @@ pub fn u8_opt(map: &BTreeMap<&str, &str>, key: &str) -> Result<Option<u8>, Token
     })
 }
 
+/// Index of the matched option from `allowed`, or `None` if the attribute is absent.
+#[inline]
+pub fn enum_opt(
+    map: &BTreeMap<&str, &str>,
+    key: &str,
+    allowed: &[&str],
+) -> Result<Option<usize>, TokenStream> {
+    Ok(match map.get(key) {
+        Some(v) => {
+            Some(A::parse_enum(key, v, allowed).map_err(|m| quote! { compile_error!(#m); })?)
+        }
+        None => None,
+    })
+}
+
+/// Positive integer attribute, e.g. a number of columns.
+pub fn count_opt(map: &BTreeMap<&str, &str>, key: &str) -> Result<Option<usize>, TokenStream> {
+    match map.get(key) {
+        None => Ok(None),
+        Some(v) => match v.trim().parse::<usize>() {
+            Ok(n) if n > 0 => Ok(Some(n)),
+            _ => {
+                let msg = format!(
+                    "efx: attribute `{}` expects a positive integer, got `{}`",
+                    key, v
+                );
+                Err(quote! { compile_error!(#msg); })
+            }
+        },
+    }
+}
+
 pub fn color_tokens_opt(
     map: &BTreeMap<&str, &str>,
     key: &str,
@@ -115,3 +221,45 @@ pub fn stroke_tokens(width: Option<f32>, color: Option<TokenStream>) -> Option<T
 
     Some(quote!( egui::Stroke { width: #w as _, color: #c } ))
 }
+
+/// Build `egui::Shadow` from `shadow="none|BLUR|X Y BLUR [SPREAD]"` and an optional color.
+/// Without a color the shadow is translucent black, as in the default egui style.
+pub fn shadow_opt(
+    map: &BTreeMap<&str, &str>,
+    key: &str,
+    color: Option<TokenStream>,
+) -> Result<Option<TokenStream>, TokenStream> {
+    let Some(v) = map.get(key) else {
+        return Ok(None);
+    };
+    if v.trim() == "none" {
+        return Ok(Some(quote!(egui::Shadow::NONE)));
+    }
+    let parts = v
+        .split_whitespace()
+        .map(|p| A::parse_f32(key, p))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|m| quote! { compile_error!(#m); })?;
+    let (x, y, blur, spread) = match parts.as_slice() {
+        [blur] => (0.0, 0.0, *blur, 0.0),
+        [x, y, blur] => (*x, *y, *blur, 0.0),
+        [x, y, blur, spread] => (*x, *y, *blur, *spread),
+        _ => {
+            let msg = format!(
+                "efx: attribute `{}` expects \"none\", \"BLUR\" or \"X Y BLUR [SPREAD]\", got `{}`",
+                key, v
+            );
+            return Err(quote! { compile_error!(#msg); });
+        }
+    };
+    let c = color.unwrap_or_else(|| quote!(egui::Color32::from_black_alpha(96)));
+
+    Ok(Some(quote! {
+        egui::Shadow {
+            offset: [#x as _, #y as _],
+            blur: #blur as _,
+            spread: #spread as _,
+            color: #c,
+        }
+    }))
+}
@@ -1,4 +1,4 @@
-use efx_core::Node;
+use efx_core::{parse_str, Element, Node};
 use proc_macro2::TokenStream;
 use quote::quote;
 
@@ -38,3 +38,45 @@ pub fn build_buffer_from_children(children: &[Node]) -> (TokenStream, TokenStrea
 
     (init, build)
 }
+
+/// Same buffer as `build_buffer_from_children`, but for a text attribute with interpolations,
+/// e.g. `text="Uploading {n}/{total}"`.
+pub fn build_buffer_from_attr(
+    name: &str,
+    src: &str,
+) -> Result<(TokenStream, TokenStream), TokenStream> {
+    match parse_str(src) {
+        Ok(nodes) => Ok(build_buffer_from_children(&nodes)),
+        Err(err) => {
+            let msg = format!("efx: attribute `{}` has invalid text: {}", name, err);
+            Err(quote! { compile_error!(#msg); })
+        }
+    }
+}
+
+/// Buffer for the text attribute `name` of `el`, if present: quoted text interpolates as in
+/// `build_buffer_from_attr`, `name={expr}` is the `Display` output of the expression.
+pub fn build_buffer_from_el(
+    el: &Element,
+    name: &str,
+) -> Result<Option<(TokenStream, TokenStream)>, TokenStream> {
+    let Some(attr) = el.attrs.iter().find(|a| a.name == name) else {
+        return Ok(None);
+    };
+    if !attr.expr {
+        return build_buffer_from_attr(name, &attr.value).map(Some);
+    }
+    match syn::parse_str::<syn::Expr>(&attr.value) {
+        Ok(expr) => Ok(Some((
+            quote! { let mut __efx_buf = ::std::string::String::new(); },
+            quote! { ::std::fmt::Write::write_fmt(&mut __efx_buf, format_args!("{}", (#expr))).ok(); },
+        ))),
+        Err(_) => {
+            let msg = format!(
+                "efx: attribute `{}` must be a valid Rust expression, got `{}`",
+                name, attr.value
+            );
+            Err(quote! { compile_error!(#msg); })
+        }
+    }
+}
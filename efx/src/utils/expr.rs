@@ -30,7 +30,6 @@ pub fn expr_req(
     }
 }
 
-#[allow(dead_code)]
 /// Optional expression attribute: `None` if absent; `compile_error!` if present but not parsed.
 pub fn expr_opt(map: &BTreeMap<&str, &str>, key: &str) -> Result<Option<syn::Expr>, TokenStream> {
     match map.get(key) {
@@ -52,3 +51,13 @@ pub fn expr_opt(map: &BTreeMap<&str, &str>, key: &str) -> Result<Option<syn::Exp
         }
     }
 }
+
+/// Mutable binding for widgets that edit state in place.
+/// `value="state.x"` becomes `&mut (state.x)`, while an explicit `value={&mut state.x}` is kept as is.
+/// Only the syntax is checked: a `&mut T` variable would become `&mut &mut T`, it is written as `&mut *x`.
+pub fn mut_ref_tokens(expr: &syn::Expr) -> TokenStream {
+    match expr {
+        syn::Expr::Reference(r) if r.mutability.is_some() => quote!( #expr ),
+        _ => quote!( &mut (#expr) ),
+    }
+}
@@ -0,0 +1,195 @@
+use crate::attr_adapters as A;
+use crate::render::render_node_stmt;
+use crate::tags::{Spacer, Tag};
+use crate::utils::render::auto_id_salt;
+use efx_core::{Element, Node};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Values of `justify` on `<Row>`/`<Column>`, in `enum_opt` order.
+pub const JUSTIFY: [&str; 5] = ["start", "center", "end", "space-between", "space-around"];
+
+/// `true` if `<Row>`/`<Column>` needs the flex pass: `justify` is given or a child has `grow`.
+pub fn is_flex(justify: Option<usize>, children: &[Node]) -> bool {
+    justify.is_some()
+        || children
+            .iter()
+            .any(|ch| matches!(ch, Node::Element(el) if el.attrs.iter().any(|a| a.name == "grow")))
+}
+
+enum FlexItem {
+    /// Laid out as usual, its main size is measured.
+    Fixed(Node),
+    /// Gets a share of the free space: `grow / total grow`.
+    Grow(f32, Option<Element>),
+}
+
+/// Children of a flex `<Row>`/`<Column>` (`horizontal` selects the main axis).
+///
+/// egui lays out in a single pass, so the main sizes of fixed children are measured on every frame and
+/// cached in `Memory` under an id generated from the template location. The next pass distributes the free
+/// space from the cached sizes: between the children by `justify`, or to the `grow` children (and
+/// `<Spacer/>`, which grows by 1) when there are any. A changed size discards the frame, so the user
+/// never sees a layout computed from stale sizes.
+pub fn render_flex_children(
+    el: &Element,
+    horizontal: bool,
+    justify: Option<usize>,
+) -> Result<TokenStream, TokenStream> {
+    let items = flex_items(&el.children)?;
+    let count = items.len();
+    let total_grow: f32 = items
+        .iter()
+        .map(|it| match it {
+            FlexItem::Grow(g, _) => *g,
+            FlexItem::Fixed(_) => 0.0,
+        })
+        .sum();
+
+    let (axis, cursor) = if horizontal {
+        (quote!(x), quote!(ui.cursor().min.x))
+    } else {
+        (quote!(y), quote!(ui.cursor().min.y))
+    };
+
+    let fixed_indices: Vec<usize> = items
+        .iter()
+        .enumerate()
+        .filter(|(_, it)| matches!(it, FlexItem::Fixed(_)))
+        .map(|(i, _)| i)
+        .collect();
+    let fixed_sum = quote!( 0.0 #( + __efx_prev.get(#fixed_indices).copied().unwrap_or(0.0) )* );
+
+    // Space before the first child and between two children (only without growing children)
+    let (lead, between) = if total_grow > 0.0 {
+        (quote!(0.0), quote!(0.0))
+    } else {
+        let n = count as f32;
+        match justify {
+            Some(1) => (quote!(__efx_free / 2.0), quote!(0.0)),
+            Some(2) => (quote!(__efx_free), quote!(0.0)),
+            Some(3) if count > 1 => (quote!(0.0), quote!(__efx_free / (#n - 1.0))),
+            Some(4) => (quote!(__efx_free / #n / 2.0), quote!(__efx_free / #n)),
+            _ => (quote!(0.0), quote!(0.0)),
+        }
+    };
+
+    let mut body = TokenStream::new();
+    for (i, item) in items.iter().enumerate() {
+        if i > 0 {
+            body.extend(quote! { ui.add_space(__efx_between); });
+        }
+        match item {
+            FlexItem::Fixed(node) => {
+                let stmt = render_node_stmt(&quote!(ui), node);
+                body.extend(quote! {
+                    let __efx_start = #cursor;
+                    #stmt
+                    __efx_sizes[#i] = (#cursor - __efx_start - __efx_gap).max(0.0);
+                });
+            }
+            FlexItem::Grow(grow, child) => {
+                let stmt = match child {
+                    Some(child) => render_node_stmt(&quote!(ui), &Node::Element(child.clone())),
+                    None => quote!(),
+                };
+                let (size, layout, min_size) = if horizontal {
+                    (
+                        quote!(egui::vec2(__efx_share, ui.spacing().interact_size.y)),
+                        quote!(egui::Layout::left_to_right(ui.layout().cross_align)),
+                        quote!(ui.set_min_width(__efx_share);),
+                    )
+                } else {
+                    (
+                        quote!(egui::vec2(ui.available_width(), __efx_share)),
+                        quote!(egui::Layout::top_down(ui.layout().cross_align)),
+                        quote!(ui.set_min_height(__efx_share);),
+                    )
+                };
+                body.extend(quote! {
+                    let __efx_share = __efx_free * (#grow / #total_grow);
+                    ui.allocate_ui_with_layout(#size, #layout.with_main_justify(true), |ui| {
+                        #min_size
+                        #stmt
+                    });
+                });
+            }
+        }
+    }
+
+    // Only bind what the generated body uses, so that no lint fires in the user's crate
+    let between_init = if count > 1 {
+        quote!( let __efx_between: f32 = #between; )
+    } else {
+        quote!()
+    };
+    let sizes_init = if fixed_indices.is_empty() {
+        quote!( let __efx_sizes = vec![0.0_f32; #count]; )
+    } else {
+        quote!( let mut __efx_sizes = vec![0.0_f32; #count]; )
+    };
+
+    let salt = auto_id_salt(el);
+    Ok(quote! {
+        let __efx_flex_id = ui.id().with(#salt);
+        let __efx_prev: Vec<f32> = ui
+            .ctx()
+            .data(|d| d.get_temp(__efx_flex_id))
+            .unwrap_or_default();
+        let __efx_gap = ui.spacing().item_spacing.#axis;
+        let __efx_avail = ui.available_size_before_wrap().#axis;
+        // Unbounded direction (e.g. inside a ScrollArea): nothing to distribute
+        let __efx_free = if __efx_avail.is_finite() {
+            (__efx_avail - (#fixed_sum) - __efx_gap * (#count as f32 - 1.0)).max(0.0)
+        } else {
+            0.0
+        };
+        #between_init
+        #sizes_init
+
+        ui.add_space(#lead);
+        #body
+
+        if __efx_sizes != __efx_prev {
+            ui.ctx().data_mut(|d| d.insert_temp(__efx_flex_id, __efx_sizes));
+            ui.ctx().request_discard("efx: flex child sizes changed");
+        }
+    })
+}
+
+/// Splits children into fixed and growing items; `grow` is removed from the child before it is rendered.
+fn flex_items(children: &[Node]) -> Result<Vec<FlexItem>, TokenStream> {
+    let mut items = Vec::new();
+    for ch in children {
+        match ch {
+            Node::Text(t) if t.value.trim().is_empty() => {}
+            Node::Element(el) if el.name == "Spacer" => {
+                Spacer::from_element(el)?;
+                items.push(FlexItem::Grow(1.0, None));
+            }
+            Node::Element(el) if el.attrs.iter().any(|a| a.name == "grow") => {
+                let mut child = el.clone();
+                let pos = child.attrs.iter().position(|a| a.name == "grow").unwrap();
+                let attr = child.attrs.remove(pos);
+                let grow = match A::parse_f32("grow", &attr.value) {
+                    Ok(g) if g >= 0.0 => g,
+                    Ok(_) => {
+                        let msg = format!(
+                            "efx: attribute `grow` must not be negative, got `{}`",
+                            attr.value
+                        );
+                        return Err(quote! { compile_error!(#msg); });
+                    }
+                    Err(msg) => return Err(quote! { compile_error!(#msg); }),
+                };
+                if grow > 0.0 {
+                    items.push(FlexItem::Grow(grow, Some(child)));
+                } else {
+                    items.push(FlexItem::Fixed(Node::Element(child)));
+                }
+            }
+            _ => items.push(FlexItem::Fixed(ch.clone())),
+        }
+    }
+    Ok(items)
+}
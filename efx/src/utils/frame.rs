@@ -0,0 +1,144 @@
+use crate::utils::attr::*;
+use efx_attrnames::AttrNames;
+use proc_macro2::TokenStream;
+use quote::quote;
+use std::collections::BTreeMap;
+
+/// `egui::Frame` attributes shared by panels, windows and `<Frame>`/`<Group>`.
+/// Tags add `FrameAttributes::ATTR_NAMES` to their own names when building the attribute map.
+#[derive(Clone, Debug, AttrNames)]
+pub struct FrameAttributes {
+    frame: Option<bool>,
+    fill: Option<TokenStream>,
+    #[attr(name = "stroke-width")]
+    stroke_width: Option<f32>,
+    #[attr(name = "stroke-color")]
+    stroke_color: Option<TokenStream>,
+    rounding: Option<f32>,
+    /// `egui::Shadow` built from `shadow` and `shadow-color`.
+    shadow: Option<TokenStream>,
+    #[attr(name = "shadow-color")]
+    shadow_color: Option<TokenStream>,
+
+    // padding (inner_margin)
+    padding: Option<f32>,
+    #[attr(name = "padding-left")]
+    padding_l: Option<f32>,
+    #[attr(name = "padding-right")]
+    padding_r: Option<f32>,
+    #[attr(name = "padding-top")]
+    padding_t: Option<f32>,
+    #[attr(name = "padding-bottom")]
+    padding_b: Option<f32>,
+
+    // margin (outer_margin)
+    margin: Option<f32>,
+    #[attr(name = "margin-left")]
+    margin_l: Option<f32>,
+    #[attr(name = "margin-right")]
+    margin_r: Option<f32>,
+    #[attr(name = "margin-top")]
+    margin_t: Option<f32>,
+    #[attr(name = "margin-bottom")]
+    margin_b: Option<f32>,
+}
+
+impl FrameAttributes {
+    pub fn from_map(map: &BTreeMap<&str, &str>) -> Result<Self, TokenStream> {
+        let shadow_color = color_tokens_opt(map, "shadow-color")?;
+        if shadow_color.is_some() && !map.contains_key("shadow") {
+            return Err(quote! {
+                compile_error!("efx: attribute `shadow-color` requires `shadow`");
+            });
+        }
+
+        Ok(FrameAttributes {
+            frame: bool_opt(map, "frame")?,
+            fill: color_tokens_opt(map, "fill")?,
+            stroke_width: f32_opt(map, "stroke-width")?,
+            stroke_color: color_tokens_opt(map, "stroke-color")?,
+            rounding: f32_opt(map, "rounding")?,
+            shadow: shadow_opt(map, "shadow", shadow_color.clone())?,
+            shadow_color,
+
+            // padding (inner_margin)
+            padding: f32_opt(map, "padding")?,
+            padding_l: f32_opt(map, "padding-left")?,
+            padding_r: f32_opt(map, "padding-right")?,
+            padding_t: f32_opt(map, "padding-top")?,
+            padding_b: f32_opt(map, "padding-bottom")?,
+
+            // margin (outer_margin)
+            margin: f32_opt(map, "margin")?,
+            margin_l: f32_opt(map, "margin-left")?,
+            margin_r: f32_opt(map, "margin-right")?,
+            margin_t: f32_opt(map, "margin-top")?,
+            margin_b: f32_opt(map, "margin-bottom")?,
+        })
+    }
+
+    /// `true` if at least one frame attribute is given.
+    pub fn is_set(&self) -> bool {
+        self.frame.is_some()
+            || self.fill.is_some()
+            || self.stroke_width.is_some()
+            || self.stroke_color.is_some()
+            || self.rounding.is_some()
+            || self.shadow.is_some()
+            || self.shadow_color.is_some()
+            || self.padding_ts().is_some()
+            || self.margin_ts().is_some()
+    }
+
+    /// Statements binding `__efx_frame`, starting from `base` (e.g. `egui::Frame::default()`).
+    /// `frame="false"` starts from `egui::Frame::NONE` instead.
+    pub fn build(&self, base: TokenStream) -> TokenStream {
+        let mut frame_build = match self.frame {
+            Some(false) => quote!( let mut __efx_frame = egui::Frame::NONE; ),
+            _ => quote!( let mut __efx_frame = #base; ),
+        };
+
+        if let Some(ts) = self.fill.clone() {
+            frame_build.extend(quote!( __efx_frame = __efx_frame.fill(#ts); ));
+        }
+        if let Some(im) = self.padding_ts() {
+            frame_build.extend(quote!( __efx_frame = __efx_frame.inner_margin(#im); ));
+        }
+        if let Some(om) = self.margin_ts() {
+            frame_build.extend(quote!( __efx_frame = __efx_frame.outer_margin(#om); ));
+        }
+        if let Some(st) = stroke_tokens(self.stroke_width, self.stroke_color.clone()) {
+            frame_build.extend(quote!( __efx_frame = __efx_frame.stroke(#st); ));
+        }
+        if let Some(r) = self.rounding {
+            frame_build.extend(quote!( __efx_frame = __efx_frame.corner_radius(#r as f32); ));
+        }
+        if let Some(sh) = self.shadow.clone() {
+            frame_build.extend(quote!( __efx_frame = __efx_frame.shadow(#sh); ));
+        }
+
+        frame_build
+    }
+
+    // Generate expressions for Padding
+    fn padding_ts(&self) -> Option<TokenStream> {
+        margin_tokens(
+            self.padding,
+            self.padding_l,
+            self.padding_r,
+            self.padding_t,
+            self.padding_b,
+        )
+    }
+
+    // Generate expressions for Margin
+    fn margin_ts(&self) -> Option<TokenStream> {
+        margin_tokens(
+            self.margin,
+            self.margin_l,
+            self.margin_r,
+            self.margin_t,
+            self.margin_b,
+        )
+    }
+}
@@ -1,4 +1,7 @@
 pub mod attr;
 pub mod buffer;
 pub mod expr;
+pub mod flex;
+pub mod frame;
 pub mod render;
+pub mod shared;
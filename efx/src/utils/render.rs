@@ -1,4 +1,5 @@
-use efx_core::Node;
+use crate::tags::{Spacer, Tag};
+use efx_core::{Element, Node};
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
 
@@ -10,3 +11,77 @@ pub fn render_children_stmt<UI: ToTokens>(ui_ident: &UI, children: &[Node]) -> T
     }
     out
 }
+
+/// Children of a container that only accepts specific child tags (e.g. `<Radio>` inside `<RadioGroup>`).
+/// Whitespace between tags is skipped; any other node results in `compile_error!`.
+pub fn child_elements<'a>(
+    children: &'a [Node],
+    tag: &str,
+    allowed: &[&str],
+) -> Result<Vec<&'a Element>, TokenStream> {
+    let mut out = Vec::new();
+    for ch in children {
+        match ch {
+            Node::Text(t) if t.value.trim().is_empty() => continue,
+            Node::Element(el) if allowed.contains(&el.name.as_str()) => out.push(el),
+            _ => {
+                let expected = allowed
+                    .iter()
+                    .map(|a| format!("<{}>", a))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let msg = format!("efx: <{}> accepts only {} children", tag, expected);
+                return Err(quote! { compile_error!(#msg); });
+            }
+        }
+    }
+    Ok(out)
+}
+
+/// Id salt derived from the template location: the `efx!` call site plus the element offset in the template,
+/// so equal tags of one template (or of two templates) get different ids.
+pub fn auto_id_salt(el: &Element) -> TokenStream {
+    let offset = el.span.start.0;
+    quote!((file!(), line!(), column!(), #offset))
+}
+
+/// Children of a `<Row>`/`<Column>`. The first `<Spacer/>` takes the free space of the main axis:
+/// the children after it are laid out from the far end (right or bottom), keeping their order on screen.
+pub fn render_flow_children<UI: ToTokens>(
+    ui: &UI,
+    children: &[Node],
+    horizontal: bool,
+) -> TokenStream {
+    let is_spacer = |ch: &Node| matches!(ch, Node::Element(el) if el.name == "Spacer");
+    let Some(pos) = children.iter().position(is_spacer) else {
+        return crate::render::render_nodes_as_stmts(ui, children);
+    };
+
+    let (before, rest) = children.split_at(pos);
+    let Node::Element(spacer) = &rest[0] else {
+        unreachable!()
+    };
+    if let Err(err) = Spacer::from_element(spacer) {
+        return err;
+    }
+    let after = &rest[1..];
+    if after.iter().any(is_spacer) {
+        return quote! { compile_error!("efx: only one <Spacer/> per <Row>/<Column> is supported"); };
+    }
+
+    let before = crate::render::render_nodes_as_stmts(ui, before);
+    let mut tail = TokenStream::new();
+    for ch in after.iter().rev() {
+        tail.extend(crate::render::render_node_stmt(&quote!(ui), ch));
+    }
+    let layout = if horizontal {
+        quote!(egui::Layout::right_to_left(#ui.layout().cross_align))
+    } else {
+        quote!(egui::Layout::bottom_up(#ui.layout().cross_align))
+    };
+
+    quote! {
+        #before
+        #ui.with_layout(#layout, |ui| { #tail });
+    }
+}
@@ -0,0 +1,288 @@
+use crate::attr_adapters as A;
+use crate::utils::attr::*;
+use crate::utils::buffer::build_buffer_from_el;
+use crate::utils::expr::expr_opt;
+use efx_attrnames::AttrNames;
+use efx_core::Element;
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+
+/// Size attribute value: points, or a percentage of the available size (`width="50%"`).
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum Size {
+    Points(f32),
+    Percent(f32),
+}
+
+impl Size {
+    fn parse(key: &str, v: &str) -> Result<Self, TokenStream> {
+        let v = v.trim();
+        let size = match v.strip_suffix('%') {
+            Some(p) => {
+                Size::Percent(A::parse_f32(key, p).map_err(|m| quote! { compile_error!(#m); })?)
+            }
+            None => Size::Points(A::parse_f32(key, v).map_err(|m| quote! { compile_error!(#m); })?),
+        };
+        match size {
+            Size::Points(n) | Size::Percent(n) if n < 0.0 => {
+                let msg = format!("efx: attribute `{}` must not be negative, got `{}`", key, v);
+                Err(quote! { compile_error!(#msg); })
+            }
+            _ => Ok(size),
+        }
+    }
+
+    /// `f32` expression; percentages refer to `__efx_avail.x` (or `.y`).
+    pub(crate) fn tokens(self, axis: TokenStream) -> TokenStream {
+        match self {
+            Size::Points(n) => quote!(#n as f32),
+            Size::Percent(p) => quote!(__efx_avail.#axis * (#p as f32 / 100.0)),
+        }
+    }
+}
+
+pub(crate) fn size_opt(
+    map: &std::collections::BTreeMap<&str, &str>,
+    key: &str,
+) -> Result<Option<Size>, TokenStream> {
+    map.get(key).map(|v| Size::parse(key, v)).transpose()
+}
+
+/// `let __efx_avail = ..;` for tags with their own size attributes, empty if none is a percentage.
+pub(crate) fn avail_tokens<UI: ToTokens>(ui: &UI, sizes: &[Option<Size>]) -> TokenStream {
+    if sizes.iter().any(|s| matches!(s, Some(Size::Percent(_)))) {
+        quote!( let __efx_avail = #ui.available_size_before_wrap(); )
+    } else {
+        TokenStream::new()
+    }
+}
+
+/// Attributes every tag inside a `Ui` accepts, applied by `render::render_tag` around the tag output.
+/// A tag keeps its own attribute of the same name (`Tag::ATTR_NAMES`).
+#[derive(Clone, Default, AttrNames)]
+pub struct SharedAttributes {
+    /// Id text with interpolations, `(init, build)` of `__efx_buf`.
+    id: Option<(TokenStream, TokenStream)>,
+    enabled: Option<syn::Expr>,
+    visible: Option<syn::Expr>,
+    /// Tooltip text with interpolations, `(init, build)` of `__efx_buf`.
+    tooltip: Option<(TokenStream, TokenStream)>,
+    width: Option<Size>,
+    height: Option<Size>,
+    #[attr(name = "min-width")]
+    min_width: Option<Size>,
+    #[attr(name = "max-width")]
+    max_width: Option<Size>,
+    #[attr(name = "min-height")]
+    min_height: Option<Size>,
+    #[attr(name = "max-height")]
+    max_height: Option<Size>,
+    #[attr(name = "fill-width")]
+    fill_width: Option<bool>,
+}
+
+/// `enabled`/`visible` condition: `{expr}` as is, a quoted value must be `true`/`false`.
+fn condition_opt(
+    el: &Element,
+    map: &BTreeMap<&str, &str>,
+    key: &str,
+) -> Result<Option<syn::Expr>, TokenStream> {
+    if el.attrs.iter().any(|a| a.name == key && a.expr) {
+        return expr_opt(map, key);
+    }
+    Ok(bool_opt(map, key)?.map(|b| syn::parse_quote!(#b)))
+}
+
+impl SharedAttributes {
+    /// Takes the shared attributes out of `el`, except the names in `own`.
+    /// The returned element is what the tag itself gets to parse.
+    pub fn split<'a>(
+        el: &'a Element,
+        own: &[&str],
+    ) -> Result<(Cow<'a, Element>, SharedAttributes), TokenStream> {
+        let is_shared =
+            |name: &str| SharedAttributes::ATTR_NAMES.contains(&name) && !own.contains(&name);
+        if !el.attrs.iter().any(|a| is_shared(&a.name)) {
+            return Ok((Cow::Borrowed(el), SharedAttributes::default()));
+        }
+
+        let shared = Element {
+            attrs: el
+                .attrs
+                .iter()
+                .filter(|a| is_shared(&a.name))
+                .cloned()
+                .collect(),
+            children: Vec::new(),
+            ..el.clone()
+        };
+        let map = attr_map(&shared, SharedAttributes::ATTR_NAMES, &el.name)?;
+        let attributes = SharedAttributes {
+            id: build_buffer_from_el(&shared, "id")?,
+            enabled: condition_opt(&shared, &map, "enabled")?,
+            visible: condition_opt(&shared, &map, "visible")?,
+            tooltip: build_buffer_from_el(&shared, "tooltip")?,
+            width: size_opt(&map, "width")?,
+            height: size_opt(&map, "height")?,
+            min_width: size_opt(&map, "min-width")?,
+            max_width: size_opt(&map, "max-width")?,
+            min_height: size_opt(&map, "min-height")?,
+            max_height: size_opt(&map, "max-height")?,
+            fill_width: bool_opt(&map, "fill-width")?,
+        };
+        if attributes.fill_width == Some(true) && attributes.width.is_some() {
+            let msg = format!("efx: <{}> has both `width` and `fill-width`", el.name);
+            return Err(quote! { compile_error!(#msg); });
+        }
+
+        let own_el = Element {
+            attrs: el
+                .attrs
+                .iter()
+                .filter(|a| !is_shared(&a.name))
+                .cloned()
+                .collect(),
+            ..el.clone()
+        };
+        Ok((Cow::Owned(own_el), attributes))
+    }
+
+    /// `true` if no shared attribute is given.
+    pub fn is_empty(&self) -> bool {
+        self.id.is_none()
+            && self.enabled.is_none()
+            && self.visible.is_none()
+            && self.tooltip.is_none()
+            && !self.has_size()
+    }
+
+    fn has_size(&self) -> bool {
+        self.width.is_some()
+            || self.height.is_some()
+            || self.min_width.is_some()
+            || self.max_width.is_some()
+            || self.min_height.is_some()
+            || self.max_height.is_some()
+            || self.fill_width == Some(true)
+    }
+
+    /// Wraps the tag output; the result is a block with the value of `inner`.
+    /// `inner` must be rendered against `ui`, the layers from the inside out: size, `enabled`,
+    /// tooltip, `visible`, `id`.
+    pub fn wrap<UI: ToTokens>(&self, ui: &UI, inner: TokenStream) -> TokenStream {
+        // Inner layers run in the closures of the outer ones, only the outermost one uses the caller's `ui`
+        let total = [
+            self.has_size(),
+            self.enabled.is_some(),
+            self.tooltip.is_some(),
+            self.visible.is_some(),
+            self.id.is_some(),
+        ]
+        .iter()
+        .filter(|on| **on)
+        .count();
+        let mut applied = 0;
+        let mut target = || {
+            applied += 1;
+            if applied == total {
+                quote!(#ui)
+            } else {
+                quote!(ui)
+            }
+        };
+
+        let mut out = inner;
+        if self.has_size() {
+            out = self.wrap_size(&target(), out);
+        }
+        if let Some(enabled) = &self.enabled {
+            let ui = target();
+            out = quote! {{
+                #ui.add_enabled_ui(#enabled, |ui| { #out }).inner
+            }};
+        }
+        if let Some((init, build)) = &self.tooltip {
+            let ui = target();
+            // A hover area registered after the content is hovered on top of its interactive widgets;
+            // outside `enabled`, it also shows over a disabled tag
+            out = quote! {{
+                let __efx_scope = #ui.scope(|ui| { #out });
+                #init
+                #build
+                let __efx_hover_id = __efx_scope.response.id.with("__efx_tooltip");
+                #ui.interact(__efx_scope.response.rect, __efx_hover_id, egui::Sense::hover())
+                    .on_hover_text(__efx_buf);
+                __efx_scope.inner
+            }};
+        }
+        if let Some(visible) = &self.visible {
+            let ui = target();
+            // Invisible content keeps its place in the layout, like `add_visible_ui`
+            out = quote! {{
+                let mut __efx_builder = egui::UiBuilder::new();
+                if !(#visible) {
+                    __efx_builder = __efx_builder.invisible();
+                }
+                #ui.scope_builder(__efx_builder, |ui| { #out }).inner
+            }};
+        }
+        if let Some((init, build)) = &self.id {
+            let ui = target();
+            out = quote! {{
+                #init
+                #build
+                #ui.push_id(__efx_buf, |ui| { #out }).inner
+            }};
+        }
+        out
+    }
+
+    fn wrap_size(&self, ui: &TokenStream, inner: TokenStream) -> TokenStream {
+        // An exact size stretches the widgets, like `ui.add_sized`
+        let width = match (self.fill_width, self.width) {
+            (Some(true), _) => Some(quote!(__efx_avail.x)),
+            (_, Some(w)) => Some(w.tokens(quote!(x))),
+            _ => None,
+        };
+        let height = self.height.map(|h| h.tokens(quote!(y)));
+        // Only the given axes are justified, the other one keeps the intrinsic size
+        let layout = match (&width, &height) {
+            (Some(_), Some(_)) => quote!(egui::Layout::centered_and_justified(
+                egui::Direction::TopDown
+            )),
+            (None, Some(_)) => quote!(egui::Layout::top_down(egui::Align::Min)
+                .with_main_align(egui::Align::Center)
+                .with_main_justify(true)),
+            (Some(_), None) => quote!(egui::Layout::top_down_justified(egui::Align::Min)),
+            (None, None) => quote!(egui::Layout::top_down(egui::Align::Min)),
+        };
+
+        let (min_w, max_w) = match &width {
+            Some(w) => (w.clone(), w.clone()),
+            None => (
+                self.min_width.map_or(quote!(0.0), |s| s.tokens(quote!(x))),
+                self.max_width
+                    .map_or(quote!(__efx_avail.x), |s| s.tokens(quote!(x))),
+            ),
+        };
+        let (min_h, max_h) = match &height {
+            Some(h) => (h.clone(), h.clone()),
+            None => (
+                self.min_height.map_or(quote!(0.0), |s| s.tokens(quote!(y))),
+                self.max_height
+                    .map_or(quote!(__efx_avail.y), |s| s.tokens(quote!(y))),
+            ),
+        };
+
+        quote! {{
+            let __efx_avail = #ui.available_size_before_wrap();
+            #ui.allocate_ui_with_layout(egui::vec2(#max_w, #max_h), #layout, |ui| {
+                ui.set_min_size(egui::vec2(#min_w, #min_h));
+                #inner
+            })
+            .inner
+        }}
+    }
+}
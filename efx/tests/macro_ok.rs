@@ -23,6 +23,18 @@ impl RecUi {
         self.ops.push(format!("button:{}", s.into()));
         DummyResponse { clicked: false }
     }
+    fn radio_value<V: PartialEq, S: Into<String>>(
+        &mut self,
+        current: &mut V,
+        alternative: V,
+        s: S,
+    ) -> DummyResponse {
+        self.ops.push(format!("radio:{}", s.into()));
+        if *current == alternative {
+            self.ops.push("radio_selected".into());
+        }
+        DummyResponse { clicked: false }
+    }
     fn separator(&mut self) {
         self.ops.push("separator".into());
     }
@@ -44,6 +56,67 @@ impl RecUi {
     }
 }
 
+/// One frame of `f` inside a central panel of `ctx`.
+fn run_frame(
+    ctx: &egui::Context,
+    input: egui::RawInput,
+    mut f: impl FnMut(&mut egui::Ui),
+) -> egui::FullOutput {
+    ctx.run(input, |ctx| {
+        egui::CentralPanel::default().show(ctx, |ui| f(ui));
+    })
+}
+
+/// A single frame of `f` inside a central panel of a fresh context.
+fn run_ui(f: impl FnMut(&mut egui::Ui)) -> egui::FullOutput {
+    run_frame(&egui::Context::default(), Default::default(), f)
+}
+
+/// Texts painted in a frame, with their screen rects.
+fn painted_texts(output: &egui::FullOutput) -> Vec<(String, egui::Rect)> {
+    output
+        .shapes
+        .iter()
+        .filter_map(|clipped| match &clipped.shape {
+            egui::Shape::Text(text) => Some((
+                text.galley.text().to_string(),
+                text.galley.rect.translate(text.pos.to_vec2()),
+            )),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Screen rect of the painted text `wanted`.
+fn text_rect(output: &egui::FullOutput, wanted: &str) -> egui::Rect {
+    let texts = painted_texts(output);
+    texts
+        .iter()
+        .find(|(text, _)| text == wanted)
+        .map(|(_, rect)| *rect)
+        .unwrap_or_else(|| panic!("`{wanted}` not painted in {texts:?}"))
+}
+
+/// Input for the two frames of a primary click at `pos`: press, then release.
+fn click_at(pos: egui::Pos2) -> [egui::RawInput; 2] {
+    let button = |pressed| egui::Event::PointerButton {
+        pos,
+        button: egui::PointerButton::Primary,
+        pressed,
+        modifiers: Default::default(),
+    };
+    [
+        egui::RawInput {
+            events: vec![egui::Event::PointerMoved(pos), button(true)],
+            ..Default::default()
+        },
+        egui::RawInput {
+            events: vec![button(false)],
+            ..Default::default()
+        },
+    ]
+}
+
 #[test]
 fn label_and_layouts_render() {
     let mut ui = RecUi::default();
@@ -75,3 +148,1042 @@ fn multiple_roots_block() {
     efx!(ui, "<Label>A</Label><Separator/><Label>{\"B\"}</Label>");
     assert_eq!(ui.ops, vec!["label:A", "separator", "label:B"]);
 }
+
+#[derive(PartialEq)]
+enum Mode {
+    Fast,
+    Slow,
+}
+
+#[test]
+fn radio_group_binds_value() {
+    let mut ui = RecUi::default();
+    let mut mode = Mode::Slow;
+    efx!(
+        ui,
+        r#"<RadioGroup value={&mut mode} direction="horizontal">
+            <Radio value={Mode::Fast}>Fast</Radio>
+            <Radio value={Mode::Slow}>Slow {1 + 1}</Radio>
+        </RadioGroup>"#
+    );
+    assert_eq!(
+        ui.ops,
+        vec![
+            "row_begin",
+            "row_children=3",
+            "radio:Fast",
+            "radio:Slow 2",
+            "radio_selected",
+            "row_end"
+        ]
+    );
+}
+
+#[test]
+fn binding_through_mut_reference() {
+    let mut name = String::from("a");
+    let binding = &mut name;
+
+    run_ui(|ui| {
+        efx!(
+            ui,
+            r#"
+            <TextField value={&mut *binding}/>
+            <TextField value="*binding"/>
+        "#
+        );
+    });
+    assert_eq!(binding, "a");
+}
+
+#[test]
+fn slider_and_drag_value_clamp_bound_values() {
+    let mut volume = 15.0_f32;
+    let mut level = -3_i32;
+    let mut scale = 20.0_f64;
+
+    run_ui(|ui| {
+        efx!(
+            ui,
+            r#"
+            <Slider value={&mut volume} min="0" max="10" step="0.5" suffix=" dB"/>
+            <Slider value="level" min="1" max="5" text="Level"/>
+            <DragValue value={&mut scale} speed="0.1" range="0..=10" prefix="x: "/>
+        "#
+        );
+    });
+    assert_eq!((volume, level, scale), (10.0, 1, 10.0));
+}
+
+#[test]
+fn combo_box_dynamic_options_display() {
+    let names = [String::from("alpha"), String::from("beta")];
+    let mut idx = 1_usize;
+
+    run_ui(|ui| {
+        efx!(
+            ui,
+            r#"
+            <Select id="names" selected={&mut idx} options={0..names.len()} as="i" display={names[i]}/>
+            <ComboBox label="Plain" selected={&mut idx} options={0..names.len()}/>
+        "#
+        );
+    });
+    assert_eq!(idx, 1);
+}
+
+#[test]
+fn progress_bar_and_spinner_paint_status() {
+    let (n, total) = (1, 4);
+    let status = "Uploading";
+    let red = egui::Color32::from_rgb(255, 0, 0);
+    let green = egui::Color32::from_rgb(0, 255, 0);
+
+    let output = run_ui(|ui| {
+        efx!(
+            ui,
+            r##"
+            <ProgressBar value={n as f32 / total as f32} text={status} width="200" fill="#ff0000"/>
+            <Spinner size="24" color="#00ff00"/>
+        "##
+        );
+    });
+
+    let fill = output
+        .shapes
+        .iter()
+        .find_map(|clipped| match &clipped.shape {
+            egui::Shape::Rect(rect) if rect.fill == red => Some(rect.rect),
+            _ => None,
+        })
+        .expect("bar fill is painted");
+    assert!(
+        (fill.width() - 50.0).abs() < 1.0,
+        "fill of {}",
+        fill.width()
+    );
+    text_rect(&output, "Uploading");
+
+    let spinner = output
+        .shapes
+        .iter()
+        .find_map(|clipped| match &clipped.shape {
+            egui::Shape::Path(path)
+                if path.stroke.color == egui::epaint::ColorMode::Solid(green) =>
+            {
+                Some(clipped.shape.visual_bounding_rect())
+            }
+            _ => None,
+        })
+        .expect("spinner is painted");
+    assert!(spinner.width() <= 24.0 + 3.0 && spinner.height() <= 24.0 + 3.0);
+    // The spinner keeps animating
+    assert!(output.viewport_output[&egui::ViewportId::ROOT]
+        .repaint_delay
+        .is_zero());
+}
+
+#[test]
+fn text_tags_style_their_labels() {
+    let n = 2;
+    let output = run_ui(|ui| {
+        ui.style_mut().text_styles.insert(
+            egui::TextStyle::Name("h3".into()),
+            egui::FontId::proportional(30.0),
+        );
+        efx!(
+            ui,
+            r#"
+            <Label>Body</Label>
+            <Heading>Title</Heading>
+            <Heading level="2">Section {n}</Heading>
+            <Heading level="3">Styled</Heading>
+            <Small>fine print</Small>
+            <Code>let x</Code>
+            <Strong>bold</Strong>
+            <Weak>muted</Weak>
+        "#
+        );
+    });
+
+    let format = |wanted: &str| {
+        output
+            .shapes
+            .iter()
+            .find_map(|clipped| match &clipped.shape {
+                egui::Shape::Text(text) if text.galley.text() == wanted => {
+                    Some(text.galley.job.sections[0].format.clone())
+                }
+                _ => None,
+            })
+            .unwrap_or_else(|| panic!("`{wanted}` not painted"))
+    };
+    let size = |wanted: &str| format(wanted).font_id.size;
+    assert_eq!(size("Title"), 18.0);
+    assert_eq!(size("Section 2"), 18.0 * 0.85);
+    // A `TextStyle::Name("h3")` entry wins over the scale
+    assert_eq!(size("Styled"), 30.0);
+    assert!(size("fine print") < size("Body"));
+    assert_eq!(format("let x").font_id.family, egui::FontFamily::Monospace);
+    let body = format("Body").color;
+    assert!(format("bold").color != body && format("muted").color != body);
+}
+
+#[test]
+fn grid_breaks_rows_by_count_and_grid_row() {
+    let output = run_ui(|ui| {
+        efx!(
+            ui,
+            r#"
+            <Grid id="props" columns="2" striped spacing="40 4">
+                <Label>name</Label><Label>Bob</Label>
+                <Label>age</Label><Label>42</Label>
+            </Grid>
+            <Grid id="rows">
+                <GridRow><Label>x</Label><Label>y</Label></GridRow>
+                <GridRow><Label>z</Label></GridRow>
+            </Grid>
+        "#
+        );
+    });
+    let rect = |wanted: &str| text_rect(&output, wanted);
+
+    let (name, bob, age, answer) = (rect("name"), rect("Bob"), rect("age"), rect("42"));
+    assert!((name.center().y - bob.center().y).abs() < 1.0);
+    assert!(bob.min.x - name.max.x >= 40.0);
+    assert!(age.min.y > name.max.y);
+    assert!((age.min.x - name.min.x).abs() < 1.0 && (answer.min.x - bob.min.x).abs() < 1.0);
+    // Striping paints the background of the second row
+    let stripe = egui::Visuals::dark().faint_bg_color;
+    assert!(output.shapes.iter().any(|clipped| matches!(
+        &clipped.shape,
+        egui::Shape::Rect(r) if r.fill == stripe && r.rect.contains(age.center())
+    )));
+
+    let (x, y, z) = (rect("x"), rect("y"), rect("z"));
+    assert!((x.center().y - y.center().y).abs() < 1.0);
+    assert!(z.min.y > x.max.y && (z.min.x - x.min.x).abs() < 1.0);
+}
+
+#[test]
+fn image_cover_crops_instead_of_stretching() {
+    let ctx = egui::Context::default();
+    // 4x2 texture into a 10x10 box: scaled to 20x10, the middle half of the width stays visible
+    let tex = ctx.load_texture(
+        "wide",
+        egui::ColorImage::new([4, 2], vec![egui::Color32::WHITE; 8]),
+        Default::default(),
+    );
+
+    let output = run_frame(&ctx, Default::default(), |ui| {
+        efx!(
+            ui,
+            r#"<Image texture={&tex} width="10" height="10" fit="cover"/>"#
+        );
+    });
+
+    let image = output
+        .shapes
+        .iter()
+        .find_map(|clipped| match &clipped.shape {
+            egui::Shape::Rect(rect) => rect
+                .brush
+                .as_ref()
+                .filter(|brush| brush.fill_texture_id == tex.id())
+                .map(|brush| (rect.rect.size(), brush.uv)),
+            _ => None,
+        })
+        .expect("image is painted");
+    assert_eq!(image.0, egui::vec2(10.0, 10.0));
+    assert_eq!((image.1.min.x, image.1.max.x), (0.25, 0.75));
+    assert_eq!((image.1.min.y, image.1.max.y), (0.0, 1.0));
+}
+
+#[test]
+fn window_shows_only_while_open() {
+    let seen = std::cell::RefCell::new(Vec::new());
+    let hit = |name: &'static str| {
+        seen.borrow_mut().push(name);
+        name
+    };
+    let mut open = true;
+    let mut closed = false;
+    let name = "Player";
+
+    run_ui(|ui| {
+        efx!(
+            ui,
+            r#"
+            <Window title="{name} settings" open={&mut open} resizable default-width="200" anchor="top-right" offset="-8 8">
+                <Label>{hit("open")}</Label>
+            </Window>
+            <Window title="Hidden" open={&mut closed}><Label>{hit("closed")}</Label></Window>
+        "#
+        );
+    });
+    assert_eq!(*seen.borrow(), vec!["open"]);
+    assert!(open && !closed);
+}
+
+#[test]
+fn panels_dock_to_their_edges() {
+    let rects = std::cell::RefCell::new(Vec::new());
+    let probe = |name: &'static str, ui: &egui::Ui| {
+        rects.borrow_mut().push((name, ui.max_rect()));
+        name
+    };
+    let screen = egui::Rect::from_min_size(egui::Pos2::ZERO, egui::vec2(800.0, 600.0));
+    let input = egui::RawInput {
+        screen_rect: Some(screen),
+        ..Default::default()
+    };
+
+    run_frame(&egui::Context::default(), input, |ui| {
+        efx!(
+            ui,
+            r#"
+            <TopPanel id="top" default-height="24"><Label>{probe("top", ui)}</Label></TopPanel>
+            <BottomPanel id="bottom" show-separator="false"><Label>{probe("bottom", ui)}</Label></BottomPanel>
+            <SidePanel id="left" resizable default-width="120"><Label>{probe("left", ui)}</Label></SidePanel>
+            <SidePanel id="right" side="right" default-width="120"><Label>{probe("right", ui)}</Label></SidePanel>
+        "#
+        );
+    });
+
+    let rects = rects.borrow();
+    let rect = |name: &str| rects.iter().find(|(n, _)| *n == name).unwrap().1;
+    assert_eq!(rects.len(), 4);
+    assert!(rect("top").top() < 20.0 && rect("top").width() > 700.0);
+    assert!(rect("bottom").bottom() > 580.0);
+    assert!(rect("left").left() < 20.0 && rect("left").width() < 200.0);
+    assert!(rect("right").right() > 780.0 && rect("right").width() < 200.0);
+}
+
+#[test]
+fn context_root_lays_out_every_panel_kind() {
+    let seen = std::cell::RefCell::new(Vec::new());
+    let hit = |name: &'static str| {
+        seen.borrow_mut().push(name);
+        name
+    };
+    let mut open = true;
+    let mut closed = false;
+
+    let ctx = egui::Context::default();
+    let _ = ctx.run(egui::RawInput::default(), |ctx| {
+        efx!(
+            ctx: ctx,
+            r#"
+            <TopPanel id="menu"><Label>{hit("top")}</Label></TopPanel>
+            <BottomPanel id="status"><Label>{hit("bottom")}</Label></BottomPanel>
+            <SidePanel id="nav"><Label>{hit("side")}</Label></SidePanel>
+            <CentralPanel><Label>{hit("central")}</Label></CentralPanel>
+            <Window title="Tools" open={&mut open}><Label>{hit("window")}</Label></Window>
+            <Window title="Closed" open={&mut closed}><Label>{hit("closed")}</Label></Window>
+        "#
+        );
+    });
+    assert_eq!(
+        *seen.borrow(),
+        vec!["top", "bottom", "side", "central", "window"]
+    );
+    assert!(open);
+}
+
+#[test]
+fn collapsing_sections_with_the_same_title_keep_distinct_ids() {
+    let ids = std::cell::RefCell::new(Vec::new());
+    let probe = |ui: &egui::Ui| {
+        ids.borrow_mut().push(ui.id());
+        "body"
+    };
+    let title = "Same";
+
+    let output = run_ui(|ui| {
+        efx!(
+            ui,
+            r#"
+            <Collapsing title={title} default-open><Label>{probe(ui)}</Label></Collapsing>
+            <Collapsing title={title} default-open><Label>{probe(ui)}</Label></Collapsing>
+        "#
+        );
+    });
+
+    let texts = painted_texts(&output);
+    assert_eq!(texts.iter().filter(|(t, _)| t == "Same").count(), 2);
+    let ids = ids.borrow();
+    assert_eq!(ids.len(), 2);
+    assert_ne!(ids[0], ids[1]);
+    // egui paints a warning over widgets that share an id
+    assert!(!texts.iter().any(|(t, _)| t.starts_with('🔥')), "{texts:?}");
+}
+
+#[test]
+fn tabs_render_only_active_body() {
+    let shown = std::cell::RefCell::new(Vec::new());
+    let hit = |name: &'static str| {
+        shown.borrow_mut().push(name);
+        name
+    };
+    let mut tab = Mode::Slow;
+
+    run_ui(|ui| {
+        efx!(
+            ui,
+            r#"
+            <Tabs selected={&mut tab} style="pills">
+                <Tab value={Mode::Fast} title="Fast"><Label>{hit("fast")}</Label></Tab>
+                <Tab value={Mode::Slow} title="Slow"><Label>{hit("slow")}</Label></Tab>
+            </Tabs>
+        "#
+        );
+    });
+    assert_eq!(*shown.borrow(), vec!["slow"]);
+}
+
+fn counted<'a, T>(value: &'a mut T, count: &std::cell::Cell<u32>) -> &'a mut T {
+    count.set(count.get() + 1);
+    value
+}
+
+#[test]
+fn tabs_evaluate_selected_once() {
+    let count = std::cell::Cell::new(0);
+    let mut tab = Mode::Slow;
+
+    let ctx = egui::Context::default();
+    let mut frame = |input| {
+        run_frame(&ctx, input, |ui| {
+            efx!(
+                ui,
+                r#"
+                <Tabs selected={&mut *counted(&mut tab, &count)}>
+                    <Tab value={Mode::Fast} title="Fast"><Label>fast</Label></Tab>
+                    <Tab value={Mode::Slow} title="Slow"><Label>slow: {tab == Mode::Slow}</Label></Tab>
+                </Tabs>
+            "#
+            );
+        })
+    };
+
+    let first = frame(Default::default());
+    let fast = text_rect(&first, "Fast").center();
+    // The switch reads and writes the binding in the same frame
+    for input in click_at(fast) {
+        frame(input);
+    }
+    assert!(tab == Mode::Fast);
+    assert_eq!(count.get(), 3);
+}
+
+#[cfg(feature = "extras")]
+#[test]
+fn table_rows_follow_sort_state() {
+    struct Person {
+        name: &'static str,
+        age: u32,
+    }
+    let people = [
+        Person {
+            name: "Bob",
+            age: 42,
+        },
+        Person {
+            name: "Alice",
+            age: 30,
+        },
+    ];
+    let shown = std::cell::RefCell::new(Vec::new());
+    let hit = |name: &'static str| {
+        shown.borrow_mut().push(name);
+        name
+    };
+    let mut sort = Some((1, true));
+
+    run_ui(|ui| {
+        efx!(
+            ui,
+            r#"
+            <Table striped resizable sort={&mut sort}>
+                <Column width="120" header="Name" sort-by={&p.name}/>
+                <Column width="remainder" header="Age" sort-by={p.age}/>
+                <Rows each={&people} as="p" height="18">
+                    <Label>{hit(p.name)}</Label>
+                    <Label>{p.age}</Label>
+                </Rows>
+            </Table>
+        "#
+        );
+    });
+    assert_eq!(*shown.borrow(), vec!["Alice", "Bob"]);
+}
+
+#[cfg(feature = "extras")]
+#[test]
+fn table_sort_key_runs_once_per_row() {
+    let values = [3, 1, 2, 5, 4];
+    let calls = std::cell::Cell::new(0);
+    let key = |v: &i32| {
+        calls.set(calls.get() + 1);
+        *v
+    };
+    let mut sort = Some((0, false));
+
+    run_ui(|ui| {
+        efx!(
+            ui,
+            r#"
+            <Table sort={&mut sort}>
+                <Column header="Value" sort-by={key(v)}/>
+                <Rows each={&values} as="v"><Label>{v}</Label></Rows>
+            </Table>
+        "#
+        );
+    });
+    assert_eq!(calls.get(), values.len());
+}
+
+#[test]
+fn menu_opens_and_runs_item_handler() {
+    let mut opened = 0;
+    let frame = |ctx: &egui::Context, input: egui::RawInput, opened: &mut i32| {
+        ctx.run(input, |ctx| {
+            efx!(
+                ctx: ctx,
+                r#"
+                <TopPanel id="menu">
+                    <MenuBar>
+                        <Menu title="File">
+                            <MenuItem onClick={|| *opened += 1} shortcut="Ctrl+O">Open</MenuItem>
+                            <MenuSeparator/>
+                            <Menu title="Recent"><MenuItem>notes.txt</MenuItem></Menu>
+                        </Menu>
+                        <Menu title="View"><MenuItem enabled={false}>Zoom</MenuItem></Menu>
+                    </MenuBar>
+                </TopPanel>
+            "#
+            );
+        })
+    };
+    let find = |output: &egui::FullOutput, text: &str| {
+        painted_texts(output)
+            .into_iter()
+            .find(|(t, _)| t == text)
+            .map(|(_, rect)| rect.center())
+    };
+
+    let ctx = egui::Context::default();
+    // The menu bar is laid out invisibly in its first frame (egui sizing pass)
+    frame(&ctx, Default::default(), &mut opened);
+    let closed = frame(&ctx, Default::default(), &mut opened);
+    assert!(find(&closed, "View").is_some());
+    assert!(find(&closed, "Open").is_none());
+
+    for input in click_at(find(&closed, "File").expect("menu button")) {
+        frame(&ctx, input, &mut opened);
+    }
+    let open = frame(&ctx, Default::default(), &mut opened);
+    assert!(find(&open, "Ctrl+O").is_some());
+
+    for input in click_at(find(&open, "Open").expect("menu is open")) {
+        frame(&ctx, input, &mut opened);
+    }
+    assert_eq!(opened, 1);
+}
+
+#[test]
+fn modal_action_button_runs_handler_and_closes() {
+    let mut open = true;
+    let mut deleted = 0;
+    let mut closed = 0;
+    let mut frame = |ctx: &egui::Context, input: egui::RawInput| {
+        ctx.run(input, |ctx| {
+            efx!(
+                ctx: ctx,
+                r#"
+                <CentralPanel><Label>Files</Label></CentralPanel>
+                <Modal id="confirm" open={&mut open} onClose={|| closed += 1}>
+                    <Label>Delete 2 files?</Label>
+                    <ModalActions>
+                        <Button onClick={|| deleted += 1}>Delete</Button>
+                        <Button>Cancel</Button>
+                    </ModalActions>
+                </Modal>
+            "#
+            );
+        })
+    };
+
+    let ctx = egui::Context::default();
+    frame(&ctx, Default::default());
+    let shown = frame(&ctx, Default::default());
+    let delete = text_rect(&shown, "Delete").center();
+    for input in click_at(delete) {
+        frame(&ctx, input);
+    }
+    let hidden = frame(&ctx, Default::default());
+    assert!(!painted_texts(&hidden).iter().any(|(t, _)| t == "Delete"));
+    assert_eq!((open, deleted, closed), (false, 1, 1));
+}
+
+#[test]
+fn color_picker_buttons_show_bound_colors() {
+    let mut srgba = egui::Color32::from_rgb(200, 10, 10);
+    let mut rgb = [0.0_f32, 1.0, 0.0];
+    let mut hsva = egui::ecolor::Hsva::from(egui::Color32::from_rgb(10, 10, 200));
+
+    let ctx = egui::Context::default();
+    let mut frame = || {
+        run_frame(&ctx, Default::default(), |ui| {
+            efx!(
+                ui,
+                r#"
+                <ColorPicker value={&mut srgba} alpha="none"/>
+                <ColorPicker value={&mut rgb} as="rgb"/>
+                <ColorPicker value={&mut hsva} as="hsva" alpha="premultiplied"/>
+            "#
+            );
+        })
+    };
+    frame();
+    let output = frame();
+
+    let fills: Vec<egui::Color32> = output
+        .shapes
+        .iter()
+        .filter_map(|clipped| match &clipped.shape {
+            egui::Shape::Rect(rect) => Some(rect.fill),
+            _ => None,
+        })
+        .collect();
+    for color in [
+        egui::Color32::from_rgb(200, 10, 10),
+        egui::Color32::from_rgb(0, 255, 0),
+        egui::Color32::from_rgb(10, 10, 200),
+    ] {
+        assert!(
+            fills.contains(&color),
+            "no swatch of {color:?} in {fills:?}"
+        );
+    }
+}
+
+#[test]
+fn frame_and_group_paint_around_children() {
+    let output = run_ui(|ui| {
+        efx!(
+            ui,
+            r##"
+            <Frame fill="#123456" padding="12" rounding="8" inner-width="180">
+                <Label>in frame</Label>
+            </Frame>
+            <Group padding="8">
+                <Label>in group</Label>
+            </Group>
+        "##
+        );
+    });
+
+    let frame_text = text_rect(&output, "in frame");
+    text_rect(&output, "in group");
+
+    let frame = output
+        .shapes
+        .iter()
+        .find_map(|clipped| match &clipped.shape {
+            egui::Shape::Rect(rect) if rect.fill == egui::Color32::from_rgb(0x12, 0x34, 0x56) => {
+                Some(rect.clone())
+            }
+            _ => None,
+        })
+        .expect("no frame filled with #123456");
+    assert!(frame.rect.contains_rect(frame_text));
+    assert_eq!(frame.corner_radius, egui::CornerRadius::same(8));
+    assert!(frame_text.min.x - frame.rect.min.x >= 12.0);
+    assert!(frame.rect.width() >= 180.0 + 2.0 * 12.0);
+}
+
+#[test]
+fn spacer_packs_tail_to_the_end_of_row() {
+    let seen = std::cell::RefCell::new(Vec::new());
+    let at = |ui: &egui::Ui| {
+        seen.borrow_mut()
+            .push((ui.layout().main_dir(), ui.max_rect().right()));
+        "x"
+    };
+
+    run_ui(|ui| {
+        efx!(
+            ui,
+            r#"<Row><Label>{at(ui)}</Label><Spacer/><Label>{at(ui)}</Label></Row>"#
+        );
+    });
+
+    let seen = seen.borrow();
+    assert_eq!(seen[0].0, egui::Direction::LeftToRight);
+    assert_eq!(seen[1].0, egui::Direction::RightToLeft);
+    assert_eq!(seen[0].1, seen[1].1);
+}
+
+#[test]
+fn layout_tags_place_children_along_their_direction() {
+    let mut panel = egui::Rect::NOTHING;
+    let output = run_ui(|ui| {
+        panel = ui.max_rect();
+        efx!(
+            ui,
+            r#"
+            <Layout dir="left-to-right">
+                <Label>first</Label>
+                <Label>second</Label>
+            </Layout>
+            <Center><Label>centered</Label></Center>
+            <RightToLeft><Label>right</Label></RightToLeft>
+        "#
+        );
+    });
+
+    let rect = |wanted: &str| text_rect(&output, wanted);
+    let (first, second) = (rect("first"), rect("second"));
+    assert!(second.min.x > first.max.x);
+    assert!((second.center().y - first.center().y).abs() < 1.0);
+
+    let centered = rect("centered");
+    assert!((centered.center().x - panel.center().x).abs() < 2.0);
+
+    let right = rect("right");
+    assert!(right.center().x > panel.center().x);
+}
+
+#[test]
+fn justify_end_uses_measured_sizes() {
+    let seen = std::cell::RefCell::new(Vec::new());
+    let at = |ui: &egui::Ui| {
+        seen.borrow_mut()
+            .push((ui.cursor().min.x, ui.max_rect().right()));
+        "end"
+    };
+
+    run_ui(|ui| {
+        efx!(ui, r#"<Row justify="end"><Label>{at(ui)}</Label></Row>"#);
+    });
+
+    // The first pass has no sizes yet and is discarded, the second one ends the label at the right edge
+    let seen = seen.borrow();
+    assert_eq!(seen.len(), 2);
+    let (x, right) = seen[1];
+    assert!(x < right && right - x < 50.0, "{x} .. {right}");
+}
+
+#[test]
+fn columns_split_children_side_by_side() {
+    let mut panel = egui::Rect::NOTHING;
+    let output = run_ui(|ui| {
+        panel = ui.max_rect();
+        efx!(
+            ui,
+            r#"
+            <Columns gap="16">
+                <Col><Label>left</Label></Col>
+                <Col><Label>right</Label></Col>
+            </Columns>
+            <Columns count="2">
+                <Label>a</Label><Label>b</Label><Label>c</Label>
+            </Columns>
+        "#
+        );
+    });
+
+    let rect = |wanted: &str| text_rect(&output, wanted);
+    let (left, right) = (rect("left"), rect("right"));
+    assert!(left.max.x < panel.center().x);
+    assert!(right.min.x >= panel.center().x);
+    assert!((left.center().y - right.center().y).abs() < 1.0);
+
+    // Plain children go round-robin: `c` lands under `a` in the first column
+    let (a, b, c) = (rect("a"), rect("b"), rect("c"));
+    assert!(b.min.x > a.max.x);
+    assert!((c.min.x - a.min.x).abs() < 1.0);
+    assert!(c.min.y > a.max.y);
+}
+
+#[test]
+fn shared_width_sizes_any_widget() {
+    run_ui(|ui| {
+        let avail = ui.available_width();
+        let half: egui::Response = efx!(ui, r#"<Button width="50%">Half</Button>"#);
+        let fixed: egui::Response = efx!(ui, r#"<Button width="120">Fixed</Button>"#);
+        assert_eq!(half.rect.width(), avail / 2.0);
+        assert_eq!(fixed.rect.width(), 120.0);
+    });
+}
+
+#[test]
+fn shared_height_keeps_intrinsic_width() {
+    run_ui(|ui| {
+        let tall: egui::Response = efx!(ui, r#"<Button height="40">Tall</Button>"#);
+        let plain: egui::Response = efx!(ui, r#"<Button>Tall</Button>"#);
+        assert_eq!(tall.rect.height(), 40.0);
+        assert_eq!(tall.rect.width(), plain.rect.width());
+    });
+}
+
+#[test]
+fn widget_sizes_accept_percentages() {
+    let mut name = String::new();
+    let mut avail = 0.0;
+
+    let output = run_ui(|ui| {
+        avail = ui.available_width();
+        efx!(
+            ui,
+            r#"
+            <TextField value={&mut name} width="50%"/>
+            <ProgressBar value="0.5" width="25%"/>
+        "#
+        );
+    });
+
+    let rects: Vec<egui::epaint::RectShape> = output
+        .shapes
+        .iter()
+        .filter_map(|clipped| match &clipped.shape {
+            egui::Shape::Rect(rect) => Some(rect.clone()),
+            _ => None,
+        })
+        .collect();
+    let has_width = |width: f32| rects.iter().any(|r| (r.rect.width() - width).abs() < 1.0);
+    // The text edit frame wraps the desired width, the bar background is exactly it
+    assert!(
+        rects.iter().any(|r| {
+            let w = r.rect.width();
+            w >= avail * 0.5 && w <= avail * 0.5 + 16.0
+        }),
+        "no text field of {} in {rects:?}",
+        avail * 0.5
+    );
+    assert!(has_width(avail * 0.25), "no bar of {}", avail * 0.25);
+}
+
+#[test]
+fn modal_action_buttons_take_shared_attributes() {
+    let mut open = true;
+    let ctx = egui::Context::default();
+    let mut frame = || {
+        ctx.run(egui::RawInput::default(), |ctx| {
+            efx!(
+                ctx: ctx,
+                r#"
+                <Modal id="sized" open={&mut open}>
+                    <ModalActions>
+                        <Button width="200" tooltip="Removes the files">Delete</Button>
+                    </ModalActions>
+                </Modal>
+            "#
+            );
+        })
+    };
+    frame();
+    let output = frame();
+
+    let button = text_rect(&output, "Delete");
+    assert!(output.shapes.iter().any(|clipped| matches!(
+        &clipped.shape,
+        egui::Shape::Rect(rect)
+            if (rect.rect.width() - 200.0).abs() < 1.0 && rect.rect.contains_rect(button)
+    )));
+}
+
+#[test]
+fn shared_enabled_and_visible_scope_any_tag() {
+    let seen = std::cell::RefCell::new(Vec::new());
+    let probe = |ui: &egui::Ui| {
+        seen.borrow_mut().push((ui.is_enabled(), ui.is_visible()));
+        "probe"
+    };
+    let locked = true;
+
+    run_ui(|ui| {
+        efx!(
+            ui,
+            r#"
+            <Label enabled={!locked}>{probe(ui)}</Label>
+            <Row visible="false" id="hidden"><Label>{probe(ui)}</Label></Row>
+            <Label tooltip="plain">{probe(ui)}</Label>
+        "#
+        );
+    });
+    // Invisible content is disabled as well, as with `ui.add_visible_ui`
+    assert_eq!(
+        *seen.borrow(),
+        vec![(false, true), (false, false), (true, true)]
+    );
+}
+
+#[test]
+fn button_takes_shared_enabled_and_tooltip() {
+    let mut flag = false;
+    let mut clicks = 0;
+    let n = 3;
+
+    let ctx = egui::Context::default();
+    ctx.style_mut(|style| {
+        style.interaction.tooltip_delay = 0.0;
+        style.interaction.show_tooltips_only_when_still = false;
+    });
+    let frame = |flag: bool, clicks: &mut i32, input| {
+        run_frame(&ctx, input, |ui| {
+            efx!(
+                ui,
+                r#"<Button enabled={flag} tooltip="n={n}" onClick={|| *clicks += 1}>Run</Button>"#
+            );
+        })
+    };
+
+    let first = frame(flag, &mut clicks, Default::default());
+    let run = text_rect(&first, "Run").center();
+
+    for input in click_at(run) {
+        frame(flag, &mut clicks, input);
+    }
+    assert_eq!(clicks, 0, "a disabled button is not clickable");
+
+    // Hit testing uses the widgets of the previous frame
+    flag = true;
+    frame(flag, &mut clicks, Default::default());
+    for input in click_at(run) {
+        frame(flag, &mut clicks, input);
+    }
+    assert_eq!(clicks, 1);
+
+    // Tooltips stay hidden right after a click, the pointer has to move later
+    let hover = egui::RawInput {
+        events: vec![egui::Event::PointerMoved(run + egui::vec2(1.0, 0.0))],
+        time: Some(1.0),
+        ..Default::default()
+    };
+    frame(flag, &mut clicks, hover);
+    let hovered = frame(flag, &mut clicks, Default::default());
+    assert!(
+        painted_texts(&hovered).iter().any(|(t, _)| t == "n=3"),
+        "no tooltip in {:?}",
+        painted_texts(&hovered)
+    );
+}
+
+#[efx::efx_component]
+fn Counter(ui: &mut egui::Ui, title: &str, count: &mut i32, step: i32, compact: bool) {
+    if !compact {
+        efx!(ui, "<Label>{title}</Label>");
+    }
+    *count += step;
+}
+
+#[test]
+fn component_renders_with_typed_props() {
+    let mut count = 1;
+    run_ui(|ui| {
+        efx!(
+            ui,
+            r#"
+            <Counter title="Clicks" count={&mut count} step="2" compact="false"/>
+            <Counter title={"Again"} count={&mut count} step={-1} compact/>
+        "#
+        );
+    });
+    assert_eq!(count, 2);
+}
+
+#[efx::efx_component]
+fn Meter(
+    ui: &mut egui::Ui,
+    label: String,
+    caption: &str,
+    ratio: f32,
+    scale: f32,
+    seen: &mut Vec<String>,
+) {
+    seen.push(format!("{label} / {caption} / {ratio} / {scale}"));
+    efx!(ui, "<Label>{label}</Label>");
+}
+
+#[test]
+fn component_props_take_numbers_and_interpolated_text() {
+    let mut seen = Vec::new();
+    let files = 3;
+    run_ui(|ui| {
+        efx!(
+            ui,
+            r#"
+            <Meter label="Files: {files}" caption="{files * 2} parts" ratio="1" scale="0.5" seen={&mut seen}/>
+            <Meter label="plain" caption="as is" ratio="-2" scale="1e1" seen={&mut seen}/>
+        "#
+        );
+    });
+    assert_eq!(
+        seen,
+        ["Files: 3 / 6 parts / 1 / 0.5", "plain / as is / -2 / 10"]
+    );
+}
+
+#[efx::efx_component]
+fn Release(
+    ui: &mut egui::Ui,
+    title: &str,
+    tag: String,
+    year: u16,
+    patch: i8,
+    weight: f64,
+    seen: &mut Vec<String>,
+) {
+    seen.push(format!("{title} / {tag} / {year} / {patch} / {weight}"));
+    efx!(ui, "<Label>{title}</Label>");
+}
+
+#[test]
+fn component_props_pick_the_literal_by_prop_type() {
+    let mut seen = Vec::new();
+    run_ui(|ui| {
+        efx!(
+            ui,
+            r#"<Release title="2024" tag="1.5" year="2024" patch="-3" weight="2" seen={&mut seen}/>"#
+        );
+    });
+    assert_eq!(seen, ["2024 / 1.5 / 2024 / -3 / 2"]);
+}
+
+#[efx::efx_component]
+fn Card(
+    ui: &mut egui::Ui,
+    title: &str,
+    #[efx_slot] actions: impl FnOnce(&mut egui::Ui),
+    #[efx_slot] children: impl FnOnce(&mut egui::Ui),
+) {
+    efx!(ui, "<Label>{title}</Label>");
+    actions(ui);
+    children(ui);
+}
+
+#[test]
+fn component_slots_receive_children() {
+    let seen = std::cell::RefCell::new(Vec::new());
+    let probe = |name: &'static str| {
+        seen.borrow_mut().push(name);
+        name
+    };
+
+    run_ui(|ui| {
+        efx!(
+            ui,
+            r#"
+            <Card title="Stats">
+                <Label>{probe("body")}</Label>
+                <Slot name="actions"><Label>{probe("action")}</Label></Slot>
+                <Label>{probe("more body")}</Label>
+            </Card>
+        "#
+        );
+    });
+    // Slots run where the component calls them, not in template order
+    assert_eq!(*seen.borrow(), vec!["action", "body", "more body"]);
+}
@@ -0,0 +1,13 @@
+use efx::efx;
+
+#[derive(Default)]
+struct Ui;
+impl Ui {
+    fn label<T>(&mut self, _t: T) {}
+}
+
+fn main() {
+    let mut ui = Ui::default();
+    // the direction of a preset is fixed
+    efx!(ui, r#"<Center dir="left-to-right"><Label>Hi</Label></Center>"#);
+}
@@ -0,0 +1,13 @@
+use efx::efx;
+
+#[derive(Default)]
+struct Ui;
+impl Ui {
+    fn label<T>(&mut self, _t: T) {}
+}
+
+fn main() {
+    let mut ui = Ui::default();
+    // the header text is required
+    efx!(ui, r#"<Collapsing id="adv"><Label>A</Label></Collapsing>"#);
+}
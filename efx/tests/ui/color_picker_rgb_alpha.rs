@@ -0,0 +1,14 @@
+use efx::efx;
+
+#[derive(Default)]
+struct Ui;
+impl Ui {
+    fn label<T>(&mut self, _t: T) {}
+}
+
+fn main() {
+    let mut ui = Ui::default();
+    let mut tint = [1.0_f32, 0.5, 0.0];
+    // [f32; 3] has no alpha channel to edit
+    efx!(ui, r#"<ColorPicker value={&mut tint} as="rgb" alpha="blend"/>"#);
+}
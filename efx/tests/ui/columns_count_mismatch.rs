@@ -0,0 +1,13 @@
+use efx::efx;
+
+#[derive(Default)]
+struct Ui;
+impl Ui {
+    fn label<T>(&mut self, _t: T) {}
+}
+
+fn main() {
+    let mut ui = Ui::default();
+    // explicit <Col> children must match the declared count
+    efx!(ui, r#"<Columns count="3"><Col><Label>A</Label></Col><Col><Label>B</Label></Col></Columns>"#);
+}
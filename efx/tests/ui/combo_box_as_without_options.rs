@@ -0,0 +1,11 @@
+use efx::efx;
+
+#[derive(Default)]
+struct Ui;
+
+fn main() {
+    let mut ui = Ui::default();
+    let mut choice = 0;
+    // `as` only names the item of an `options` list
+    efx!(ui, r#"<ComboBox id="c" selected={&mut choice} as="n"><Option value="0">Zero</Option></ComboBox>"#);
+}
@@ -0,0 +1,11 @@
+use efx::efx;
+
+#[derive(Default)]
+struct Ui;
+
+fn main() {
+    let mut ui = Ui::default();
+    let mut choice = 0;
+    // `options` and <Option> children are mutually exclusive
+    efx!(ui, r#"<ComboBox id="c" selected={&mut choice} options={0..3}><Option value="0">Zero</Option></ComboBox>"#);
+}
@@ -0,0 +1,20 @@
+use efx::{efx, efx_component};
+
+#[derive(Default)]
+struct Ui;
+impl Ui {
+    fn label<S: Into<String>>(&mut self, _s: S) {}
+    fn vertical<F: FnOnce(&mut Ui)>(&mut self, f: F) { let mut inner = Ui::default(); f(&mut inner); }
+}
+
+#[efx_component]
+fn Toolbar(ui: &mut Ui, title: &str, #[efx_slot] actions: impl FnOnce(&mut Ui)) {
+    ui.label(title);
+    actions(ui);
+}
+
+fn main() {
+    let mut ui = Ui::default();
+    // A slot without <Slot name="actions"> is a missing field of `ToolbarProps`
+    efx!(ui, r#"<Column><Toolbar title="Files"/></Column>"#);
+}
@@ -0,0 +1,13 @@
+use efx::efx;
+
+#[derive(Default)]
+struct Ui;
+impl Ui {
+    fn label<S: Into<String>>(&mut self, _s: S) {}
+}
+
+fn main() {
+    let mut ui = Ui::default();
+    // An unknown PascalCase tag is looked up as a component: rustc reports the missing `ToolbarProps`
+    efx!(ui, r#"<Toolbar title="Files"/>"#);
+}
@@ -0,0 +1,19 @@
+use efx::{efx, efx_component};
+
+#[derive(Default)]
+struct Ui;
+impl Ui {
+    fn label<S: Into<String>>(&mut self, _s: S) {}
+    fn vertical<F: FnOnce(&mut Ui)>(&mut self, f: F) { let mut inner = Ui::default(); f(&mut inner); }
+}
+
+#[efx_component]
+fn Toolbar(ui: &mut Ui, title: &str) {
+    ui.label(title);
+}
+
+fn main() {
+    let mut ui = Ui::default();
+    // A prop the component does not declare is a rustc error on `ToolbarProps`
+    efx!(ui, r#"<Column><Toolbar title="Files" subtitle="All"/></Column>"#);
+}
@@ -0,0 +1,7 @@
+use efx::efx;
+
+fn main() {
+    let ctx = ();
+    // widgets need a panel or a window around them
+    efx!(ctx: ctx, r#"<Label>Hello</Label>"#);
+}
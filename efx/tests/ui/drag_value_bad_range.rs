@@ -0,0 +1,14 @@
+use efx::efx;
+
+#[derive(Default)]
+struct Ui;
+impl Ui {
+    fn add<T>(&mut self, _w: T) {}
+}
+
+fn main() {
+    let mut ui = Ui::default();
+    let mut v = 0_i32;
+    // only inclusive ranges `min..=max` are accepted
+    efx!(ui, r#"<DragValue value={&mut v} range="0..10"/>"#);
+}
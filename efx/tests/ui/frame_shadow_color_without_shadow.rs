@@ -0,0 +1,13 @@
+use efx::efx;
+
+#[derive(Default)]
+struct Ui;
+impl Ui {
+    fn label<T>(&mut self, _t: T) {}
+}
+
+fn main() {
+    let mut ui = Ui::default();
+    // the color alone does not draw a shadow
+    efx!(ui, r#"<Frame shadow-color="black"><Label>Card</Label></Frame>"#);
+}
@@ -0,0 +1,13 @@
+use efx::efx;
+
+#[derive(Default)]
+struct Ui;
+impl Ui {
+    fn label<T>(&mut self, _t: T) {}
+}
+
+fn main() {
+    let mut ui = Ui::default();
+    // egui::Grid needs an id salt
+    efx!(ui, r#"<Grid columns="2"><Label>A</Label><Label>B</Label></Grid>"#);
+}
@@ -0,0 +1,7 @@
+use efx::efx;
+use efx_core::doc_prelude::*;
+
+fn main() {
+    let mut ui = Ui::default();
+    efx!(ui, r#"<Image src="file://logo.png" width="64" fit="cover"/>"#);
+}
@@ -0,0 +1,10 @@
+use efx::efx;
+
+#[derive(Default)]
+struct Ui;
+
+fn main() {
+    let mut ui = Ui::default();
+    // one of `src`, `texture` or `bytes` is required
+    efx!(ui, r#"<Image width="64" alt="Logo"/>"#);
+}
@@ -0,0 +1,13 @@
+use efx::efx;
+
+#[derive(Default)]
+struct Ui;
+impl Ui {
+    fn label<T>(&mut self, _t: T) {}
+}
+
+fn main() {
+    let mut ui = Ui::default();
+    // items are closed together with their menu
+    efx!(ui, r#"<MenuItem shortcut="Ctrl+O">Open</MenuItem>"#);
+}
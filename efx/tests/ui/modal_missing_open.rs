@@ -0,0 +1,13 @@
+use efx::efx;
+
+#[derive(Default)]
+struct Ui;
+impl Ui {
+    fn label<T>(&mut self, _t: T) {}
+}
+
+fn main() {
+    let mut ui = Ui::default();
+    // a modal is shown while its flag is set
+    efx!(ui, r#"<Modal id="confirm"><Label>Sure?</Label></Modal>"#);
+}
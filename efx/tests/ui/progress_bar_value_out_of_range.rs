@@ -0,0 +1,10 @@
+use efx::efx;
+
+#[derive(Default)]
+struct Ui;
+
+fn main() {
+    let mut ui = Ui::default();
+    // A fraction, not a percentage
+    efx!(ui, r#"<ProgressBar value="75"/>"#);
+}
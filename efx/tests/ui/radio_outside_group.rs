@@ -0,0 +1,10 @@
+use efx::efx;
+
+#[derive(Default)]
+struct Ui;
+
+fn main() {
+    let mut ui = Ui::default();
+    // <Radio> is only valid as a child of <RadioGroup>
+    efx!(ui, r#"<Radio value="1">One</Radio>"#);
+}
@@ -0,0 +1,13 @@
+use efx::efx;
+
+#[derive(Default)]
+struct Ui;
+impl Ui {
+    fn label<T>(&mut self, _t: T) {}
+}
+
+fn main() {
+    let mut ui = Ui::default();
+    // wrapped rows have no single line to distribute
+    efx!(ui, r#"<Row wrap justify="center"><Label>A</Label><Label>B</Label></Row>"#);
+}
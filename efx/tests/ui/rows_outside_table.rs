@@ -0,0 +1,14 @@
+use efx::efx;
+
+#[derive(Default)]
+struct Ui;
+impl Ui {
+    fn label<T>(&mut self, _t: T) {}
+}
+
+fn main() {
+    let mut ui = Ui::default();
+    let data = [1, 2];
+    // rows are virtualised by the table around them
+    efx!(ui, r#"<Rows each={&data}><Label>{row}</Label></Rows>"#);
+}
@@ -0,0 +1,14 @@
+use efx::efx;
+
+#[derive(Default)]
+struct Ui;
+impl Ui {
+    fn add<T>(&mut self, _w: T) {}
+}
+
+fn main() {
+    let mut ui = Ui::default();
+    let mut v = 0.0_f32;
+    // min must not exceed max
+    efx!(ui, r#"<Slider value={&mut v} min="10" max="0"/>"#);
+}
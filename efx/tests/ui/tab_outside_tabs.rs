@@ -0,0 +1,13 @@
+use efx::efx;
+
+#[derive(Default)]
+struct Ui;
+impl Ui {
+    fn label<T>(&mut self, _t: T) {}
+}
+
+fn main() {
+    let mut ui = Ui::default();
+    // a tab needs its strip
+    efx!(ui, r#"<Tab value={1} title="One"><Label>A</Label></Tab>"#);
+}
@@ -0,0 +1,12 @@
+use efx::efx;
+
+#[derive(Default)]
+struct Ui;
+
+fn main() {
+    let mut ui = Ui::default();
+    let mut name = String::new();
+    let hint = "Name";
+    // A text attribute would show the expression source, not its value
+    efx!(ui, r#"<TextField value={&mut name} hint={hint}/>"#);
+}
@@ -0,0 +1,13 @@
+use efx::efx;
+
+#[derive(Default)]
+struct Ui;
+impl Ui {
+    fn label<T>(&mut self, _t: T) {}
+}
+
+fn main() {
+    let mut ui = Ui::default();
+    // top/bottom panels are sized by height
+    efx!(ui, r#"<TopPanel id="top" default-width="100"><Label>A</Label></TopPanel>"#);
+}
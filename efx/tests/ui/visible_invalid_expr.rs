@@ -0,0 +1,13 @@
+use efx::efx;
+
+#[derive(Default)]
+struct Ui;
+impl Ui {
+    fn label<T>(&mut self, _t: T) {}
+}
+
+fn main() {
+    let mut ui = Ui::default();
+    // shared attributes are checked like the tag's own ones
+    efx!(ui, r#"<Label visible={if}>Hi</Label>"#);
+}
@@ -0,0 +1,13 @@
+use efx::efx;
+
+#[derive(Default)]
+struct Ui;
+impl Ui {
+    fn label<T>(&mut self, _t: T) {}
+}
+
+fn main() {
+    let mut ui = Ui::default();
+    // offset is relative to the anchor
+    efx!(ui, r#"<Window title="Tools" offset="-8 8"><Label>A</Label></Window>"#);
+}